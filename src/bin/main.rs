@@ -1,35 +1,13 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use structopt::StructOpt;
 
-use std::error::Error;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{BufReader, BufWriter};
-
-use serde::Deserialize;
+use std::process;
 
 extern crate myca;
 use myca::*;
 
-#[derive(Deserialize)]
-struct Courses {
-    courses: Vec<Course>,
-}
-
-pub fn parse_catalog(fname: &str) -> Result<Catalog, Box<dyn Error>> {
-    let file = File::open(fname)?;
-
-    let buf_reader = BufReader::new(file);
-    let courses: Courses = serde_json::from_reader(buf_reader)?;
-
-    let mut catalog = Catalog::new();
-
-    for course in courses.courses {
-        catalog.add_course(course);
-    }
-
-    Ok(catalog)
-}
-
 pub fn load_schedules(schedule_fname: &Option<PathBuf>) -> Vec<Schedule> {
     match schedule_fname {
         None => vec![Schedule::new()],
@@ -44,10 +22,13 @@ pub fn load_schedules(schedule_fname: &Option<PathBuf>) -> Vec<Schedule> {
             };
 
             let buf_reader = BufReader::new(file);
-            match serde_json::from_reader(buf_reader) {
-                Ok(schedule) => schedule,
+            let parsed = serde_json::from_reader(buf_reader)
+                .map_err(ScheduleFileError::from)
+                .and_then(schedules_from_json);
+            match parsed {
+                Ok(schedules) => schedules,
                 Err(err) => {
-                    eprintln!("Error parsing the current schedule: {:?}", err);
+                    eprintln!("Error parsing the current schedule: {}", err);
                     eprintln!("Defaulting to an empty schedule");
                     vec![Schedule::new()]
                 }
@@ -56,11 +37,78 @@ pub fn load_schedules(schedule_fname: &Option<PathBuf>) -> Vec<Schedule> {
     }
 }
 
+/// Reads course IDs from a file with one ID per line.  Blank lines and lines
+/// starting with `#` are skipped.
+pub fn read_courses_file(fname: &Path) -> Vec<String> {
+    let contents = fs::read_to_string(fname).unwrap_or_else(|err| {
+        eprintln!("Error reading courses file {}: {}", fname.display(), err);
+        process::exit(1);
+    });
+
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect()
+}
+
+/// Prints why a course could not be added to any schedule: any missing or
+/// undefined courses in its tree, then the first reason it is blocked from
+/// a semester.
+pub fn explain_failure(coid: &CourseID, catalog: &Catalog, schedules: &[Schedule]) {
+    let (tree, unresolved) = match catalog.get_course_tree(coid) {
+        Ok(tree) => tree,
+        Err(err) => {
+            println!("\t{}", err);
+            return;
+        }
+    };
+
+    for missing in unresolved {
+        println!("\t{} is not in the catalog", missing);
+    }
+
+    let tree_ids: Vec<CourseID> = tree.iter().map(|course| course.get_id()).collect();
+    for warning in catalog.validate() {
+        if let CatalogWarning::UndefinedPrereq { course, .. } = &warning {
+            if tree_ids.contains(course) {
+                println!("\t{}", warning);
+            }
+        }
+    }
+
+    let schedule = match schedules.first() {
+        Some(schedule) => schedule,
+        None => return,
+    };
+
+    if schedule.semesters().is_empty() {
+        println!(
+            "\tThe current schedule has no semesters to place {} in",
+            coid
+        );
+        return;
+    }
+
+    // The first blocked term is enough to explain the failure, and keeps
+    // the output short on long schedules
+    let config = GenerationConfig::default();
+    let blocked = schedule.semester_times().into_iter().find_map(|time| {
+        schedule
+            .explain_placement(coid, &time, catalog, &config)
+            .map(|issue| (time, issue))
+    });
+    if let Some((time, issue)) = blocked {
+        println!("\t{}: {}", time, issue);
+    }
+}
+
 /// CLI Options
 #[derive(StructOpt, Debug)]
 #[structopt(name = "rpi_planner")]
 struct Opt {
-    /// Catalog file
+    /// Catalog file, or a directory of catalog files
     #[structopt(short = "c", long = "catalog", parse(from_os_str))]
     catalog: PathBuf,
 
@@ -72,6 +120,20 @@ struct Opt {
     #[structopt(short = "o", long = "output", parse(from_os_str))]
     output: Option<PathBuf>,
 
+    /// Explain why no schedules could be found for a course
+    #[structopt(long = "explain")]
+    explain: bool,
+
+    /// Only validate the catalog, exiting with an error if it has problems
+    /// which prevent scheduling
+    #[structopt(long = "validate-only")]
+    validate_only: bool,
+
+    /// File of courses to add to schedule, one per line, added after any
+    /// courses given as arguments
+    #[structopt(long = "courses-file", parse(from_os_str))]
+    courses_file: Option<PathBuf>,
+
     /// Courses to add to schedule
     #[structopt(name = "COURSE")]
     courses: Vec<String>,
@@ -79,13 +141,36 @@ struct Opt {
 
 fn main() {
     let opt = Opt::from_args();
-    let catalog_fname = opt.catalog.to_str().unwrap();
-    let catalog =
-        parse_catalog(catalog_fname).unwrap_or_else(|err| panic!("Error parsing catalog: {}", err));
+    let catalog = parse_catalog(&opt.catalog).unwrap_or_else(|err| {
+        eprintln!("Error parsing catalog: {}", err);
+        process::exit(1);
+    });
+
+    if opt.validate_only {
+        let warnings = catalog.validate();
+        for warning in &warnings {
+            let kind = if warning.is_error() {
+                "Error"
+            } else {
+                "Warning"
+            };
+            println!("{}: {}", kind, warning);
+        }
+
+        if warnings.iter().any(CatalogWarning::is_error) {
+            process::exit(1);
+        }
+        return;
+    }
 
     let mut schedules = load_schedules(&opt.schedule);
 
-    for input_coid in opt.courses {
+    let mut courses = opt.courses;
+    if let Some(courses_file) = &opt.courses_file {
+        courses.extend(read_courses_file(courses_file));
+    }
+
+    for input_coid in courses {
         let coid = match CourseID::from(&input_coid) {
             Some(id) => id,
             None => {
@@ -93,9 +178,20 @@ fn main() {
                 continue;
             }
         };
+        let previous_schedules = if opt.explain {
+            Some(schedules.clone())
+        } else {
+            None
+        };
+
         schedules = get_schedules(&coid, &catalog, schedules);
 
         println!("Found {} schedule(s) for {}:", schedules.len(), coid);
+        if schedules.is_empty() {
+            if let Some(previous_schedules) = previous_schedules {
+                explain_failure(&coid, &catalog, &previous_schedules);
+            }
+        }
         for (i, schedule) in schedules.iter().enumerate() {
             println!("Schedule {}:", i + 1);
             println!("{}", schedule);
@@ -103,9 +199,20 @@ fn main() {
     }
 
     if let Some(output_file) = opt.output {
-        let file = File::open(output_file).expect("Unable to open output file");
+        let file = File::create(&output_file).unwrap_or_else(|err| {
+            eprintln!("Unable to open output file: {}", err);
+            process::exit(1);
+        });
+
+        let plans = GeneratedPlans {
+            config: GenerationConfig::default(),
+            schedules,
+        };
 
         let buf_writer = BufWriter::new(file);
-        serde_json::to_writer(buf_writer, &schedules).expect("Error writing to output file");
+        if let Err(err) = serde_json::to_writer(buf_writer, &plans) {
+            eprintln!("Error writing to output file: {}", err);
+            process::exit(1);
+        }
     }
 }
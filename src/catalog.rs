@@ -1,4 +1,14 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::Deserialize;
+
+use crate::schedule::{Season, SemTime};
 
 use course::*;
 
@@ -6,6 +16,12 @@ use course::*;
 #[derive(Default)]
 pub struct Catalog {
     courses: HashMap<CourseID, Course>,
+    /// Maps courses from other institutions to their local equivalents.
+    equivalencies: HashMap<CourseID, CourseID>,
+    /// Set once any course which substitutes for another has been added.
+    has_substitutes: bool,
+    /// Courses which listed themselves as a requisite when they were added.
+    self_references: HashSet<CourseID>,
 }
 
 impl Catalog {
@@ -13,6 +29,9 @@ impl Catalog {
     pub fn new() -> Self {
         Catalog {
             courses: HashMap::new(),
+            equivalencies: HashMap::new(),
+            has_substitutes: false,
+            self_references: HashSet::new(),
         }
     }
 
@@ -23,6 +42,11 @@ impl Catalog {
     /// If the course already exists, it will be overwritten except for
     /// its post_options.
     ///
+    /// If the course lists itself as a prerequisite or corequisite, that
+    /// requirement is dropped and `validate` reports it as
+    /// `CatalogWarning::SelfPrereq`.  A requirement group which only
+    /// contained the course itself is removed entirely.
+    ///
     /// # Examples
     ///
     /// ```
@@ -53,18 +77,42 @@ impl Catalog {
     ///
     /// assert!(catalog.get_course(&coid).is_some());
     /// ```
+    ///
+    /// A course which requires itself has that requirement dropped:
+    ///
+    /// ```
+    /// # use myca::{Catalog, CatalogWarning, CourseBuilder, CourseID};
+    /// #
+    /// let mut catalog = Catalog::new();
+    /// let coid = CourseID::new("TEST", 1100);
+    ///
+    /// catalog.add_course(CourseBuilder::new(&coid).name("Loop").prereq_group(vec![coid.clone()]).build());
+    ///
+    /// assert!(catalog.get_course(&coid).unwrap().prereq_sets().is_empty());
+    /// assert_eq!(catalog.validate(), vec![CatalogWarning::SelfPrereq(coid)]);
+    /// ```
     pub fn add_course(&mut self, mut course: Course) {
+        if course.remove_self_references() {
+            self.self_references.insert(course.get_id());
+        } else {
+            self.self_references.remove(&course.coid);
+        }
+
         if let Some(existing_course) = self.get_course(&course.get_id()) {
             course.post_options = existing_course.post_options.clone();
             self.courses.remove(&course.get_id());
         }
 
+        if course.substitutes_for.is_some() {
+            self.has_substitutes = true;
+        }
+
         for coid in course.prereqs.iter().flatten() {
             match self.get_course_mut(coid) {
-                Some(found_course) => found_course.add_postoption(&coid),
+                Some(found_course) => found_course.add_postoption(&course.coid),
                 None => {
-                    let mut new_course = Course::new(&coid);
-                    new_course.add_postoption(&coid);
+                    let mut new_course = Course::placeholder(coid);
+                    new_course.add_postoption(&course.coid);
                     self.courses.insert(coid.clone(), new_course);
                 }
             }
@@ -73,9 +121,158 @@ impl Catalog {
         self.courses.insert(course.get_id(), course);
     }
 
+    /// Reads a catalog from CSV.  Each line describes one course with the
+    /// columns:
+    ///
+    /// ```text
+    /// subject,code,name,credits,prereqs
+    /// ```
+    ///
+    /// - `credits` is either a single number (`4`) or a range (`1-4`), and
+    ///   may be left empty for courses without credits.
+    /// - `prereqs` is a semicolon-separated list of prerequisite groups,
+    ///   every one of which is required.  Each group is a `|`-separated list
+    ///   of course IDs, only one of which is required.  For example,
+    ///   `CSCI 1100|CSCI 1010;MATH 1010` requires MATH 1010 and either
+    ///   CSCI 1100 or CSCI 1010.
+    ///
+    /// Fields containing commas may be wrapped in double quotes, with a
+    /// literal quote written as `""`.  Blank lines are skipped, as is a
+    /// first line starting with `subject`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, CreditRange};
+    /// #
+    /// let csv = "subject,code,name,credits,prereqs
+    /// CSCI,1100,\"Computer Science I\",4,
+    /// CSCI,1200,Data Structures,4,CSCI 1100
+    /// ";
+    ///
+    /// let catalog = Catalog::import_csv(csv.as_bytes()).unwrap();
+    ///
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let data_structures = CourseID::new("CSCI", 1200);
+    ///
+    /// let course = catalog.get_course(&intro).unwrap();
+    /// assert_eq!(format!("{}", course), "CSCI 1100: Computer Science I");
+    /// assert_eq!(course.credits(), CreditRange::fixed(4));
+    ///
+    /// assert!(catalog.get_course(&data_structures).is_some());
+    /// assert_eq!(catalog.prereq_edges(), vec![(intro, data_structures)]);
+    /// ```
+    pub fn import_csv<R: Read>(mut reader: R) -> Result<Catalog, CatalogError> {
+        let mut contents = String::new();
+        reader
+            .read_to_string(&mut contents)
+            .map_err(CatalogError::Read)?;
+
+        let mut catalog = Catalog::new();
+
+        for (i, line) in contents.lines().enumerate() {
+            let line_num = i + 1;
+            let error = |message: String| CatalogError::Csv {
+                line: line_num,
+                message,
+            };
+
+            if line.trim().is_empty() || (i == 0 && line.starts_with("subject")) {
+                continue;
+            }
+
+            let fields =
+                split_csv_line(line).ok_or_else(|| error(String::from("unclosed quote")))?;
+            if fields.len() != 5 {
+                return Err(error(format!("expected 5 fields, found {}", fields.len())));
+            }
+
+            let subj = fields[0].trim();
+            let code = fields[1]
+                .trim()
+                .parse::<u16>()
+                .map_err(|_| error(format!("'{}' is not a course code", fields[1])))?;
+            let coid = CourseID::from(&format!("{} {}", subj, code))
+                .ok_or_else(|| error(format!("'{}' is not a course subject", subj)))?;
+
+            let credits = fields[3].trim();
+            let credits = if credits.is_empty() {
+                CreditRange::default()
+            } else {
+                let parse = |num: &str| {
+                    num.trim()
+                        .parse::<u8>()
+                        .map_err(|_| error(format!("'{}' is not a number of credits", credits)))
+                };
+                match credits.find('-') {
+                    Some(pos) => {
+                        CreditRange::new(parse(&credits[..pos])?, parse(&credits[pos + 1..])?)
+                    }
+                    None => CreditRange::fixed(parse(credits)?),
+                }
+            };
+
+            let mut builder = CourseBuilder::new(&coid)
+                .name(fields[2].trim())
+                .credits(credits);
+
+            for group in fields[4]
+                .split(';')
+                .filter(|group| !group.trim().is_empty())
+            {
+                let group = group
+                    .split('|')
+                    .map(|prereq| {
+                        CourseID::from(prereq.trim())
+                            .ok_or_else(|| error(format!("'{}' is not a course ID", prereq.trim())))
+                    })
+                    .collect::<Result<Vec<CourseID>, CatalogError>>()?;
+                builder = builder.prereq_group(group);
+            }
+
+            catalog.add_course(builder.build());
+        }
+
+        Ok(catalog)
+    }
+
+    /// Adds a new course to the catalog like `add_course`, but refuses to
+    /// overwrite an existing complete course.  Placeholder courses may still
+    /// be replaced.  If the course is rejected it is returned, and the
+    /// catalog is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID};
+    /// #
+    /// let mut catalog = Catalog::new();
+    /// let coid = CourseID::new("TEST", 1100);
+    ///
+    /// let original = CourseBuilder::new(&coid).name("Original").build();
+    /// let duplicate = CourseBuilder::new(&coid).name("Duplicate").build();
+    ///
+    /// assert!(catalog.try_add_course(original).is_ok());
+    ///
+    /// let rejected = catalog.try_add_course(duplicate).unwrap_err();
+    /// assert_eq!(format!("{}", rejected), "TEST 1100: Duplicate");
+    /// assert_eq!(format!("{}", catalog.get_course(&coid).unwrap()), "TEST 1100: Original");
+    /// ```
+    #[allow(clippy::result_large_err)] // the rejected course is handed back by value
+    pub fn try_add_course(&mut self, course: Course) -> Result<(), Course> {
+        match self.get_course(&course.coid) {
+            Some(existing) if existing.complete => Err(course),
+            _ => {
+                self.add_course(course);
+                Ok(())
+            }
+        }
+    }
+
     /// Generates a new course in place given a course ID.  As the course
     /// is made only from its ID, it will have no prerequisites or
-    /// associated information.
+    /// associated information.  Unlike the placeholders added for unknown
+    /// requisites, the course is marked complete.
     ///
     /// # Examples
     ///
@@ -88,6 +285,7 @@ impl Catalog {
     /// catalog.emplace_course(&coid);
     ///
     /// assert!(catalog.get_course(&coid).is_some());
+    /// assert_eq!(catalog.courses_missing_metadata().len(), 1);
     /// ```
     pub fn emplace_course(&mut self, coid: &CourseID) {
         self.add_course(Course::new(coid));
@@ -114,114 +312,2739 @@ impl Catalog {
         self.courses.get(coid)
     }
 
+    /// Returns a reference to the course, first adding an incomplete
+    /// placeholder course made only from its ID if it is not in the
+    /// catalog.  Unlike `get_course`, this always returns a course, but it
+    /// mutates the catalog when the course is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID};
+    /// #
+    /// let mut catalog = Catalog::new();
+    /// let coid = CourseID::new("TEST", 1100);
+    ///
+    /// assert!(catalog.get_course(&coid).is_none());
+    /// assert_eq!(catalog.get_course_or_stub(&coid).get_id(), coid);
+    /// assert!(catalog.get_course(&coid).is_some());
+    /// ```
+    pub fn get_course_or_stub(&mut self, coid: &CourseID) -> &Course {
+        self.courses
+            .entry(coid.clone())
+            .or_insert_with(|| Course::placeholder(coid))
+    }
+
     /// Returns a mutable reference to a course if it exists in the
     /// catalog, or `None` if it is not found.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use myca::{Catalog, CourseID};
+    /// # use myca::{Catalog, CourseID};
+    /// #
+    /// let mut catalog = Catalog::new();
+    /// let coid = CourseID::new("TEST", 1200);
+    ///
+    /// catalog.emplace_course(&coid);
+    ///
+    /// let course = catalog.get_course_mut(&coid).unwrap();
+    ///
+    /// assert_eq!(course.get_id(), coid);
+    ///
+    /// course.add_prereq(&CourseID::new("TEST", 1100));
+    /// ```
+    pub fn get_course_mut(&mut self, coid: &CourseID) -> Option<&mut Course> {
+        self.courses.get_mut(coid)
+    }
+
+    /// Returns the given course followed by every course it transitively
+    /// requires as a prerequisite, in breadth-first order.  Prerequisites
+    /// which cannot be found in the catalog are skipped and returned in the
+    /// second vector instead, so a partial catalog still yields a partial
+    /// tree.
+    ///
+    /// Returns `Error::CourseNotFound` if the given course itself is not in
+    /// the catalog.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, Error};
+    /// #
+    /// let mut catalog = Catalog::new();
+    /// let intro = CourseID::new("TEST", 1100);
+    /// let advanced = CourseID::new("TEST", 2100);
+    /// let missing = CourseID::new("MISS", 1000);
+    ///
+    /// catalog.emplace_course(&intro);
+    /// catalog.emplace_course(&advanced);
+    ///
+    /// let course = catalog.get_course_mut(&advanced).unwrap();
+    /// course.add_prereq(&intro);
+    /// course.add_prereq(&missing);
+    ///
+    /// let (tree, unresolved) = catalog.get_course_tree(&advanced).unwrap();
+    /// let tree_ids: Vec<CourseID> = tree.iter().map(|course| course.get_id()).collect();
+    ///
+    /// assert_eq!(tree_ids, vec![advanced, intro]);
+    /// assert_eq!(unresolved, vec![missing.clone()]);
+    ///
+    /// match catalog.get_course_tree(&missing) {
+    ///     Err(Error::CourseNotFound(coid)) => assert_eq!(coid, missing),
+    ///     _ => panic!("expected CourseNotFound"),
+    /// }
+    /// ```
+    pub fn get_course_tree(
+        &self,
+        coid: &CourseID,
+    ) -> Result<(Vec<&Course>, Vec<CourseID>), crate::Error> {
+        if self.get_course(coid).is_none() {
+            return Err(crate::Error::CourseNotFound(coid.clone()));
+        }
+
+        let mut tree = Vec::new();
+        let mut unresolved = Vec::new();
+
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        visited.insert(coid.clone());
+        queue.push_back(coid.clone());
+
+        while let Some(curr) = queue.pop_front() {
+            let course = match self.get_course(&curr) {
+                Some(course) => course,
+                None => {
+                    unresolved.push(curr);
+                    continue;
+                }
+            };
+
+            tree.push(course);
+
+            let mut prereqs: Vec<&CourseID> = course.prereqs.iter().flatten().collect();
+            prereqs.sort();
+            for prereq in prereqs {
+                if visited.insert(prereq.clone()) {
+                    queue.push_back(prereq.clone());
+                }
+            }
+        }
+
+        Ok((tree, unresolved))
+    }
+
+    /// Returns every course the given course transitively requires as a
+    /// prerequisite, not including the course itself.  Every option of
+    /// each prerequisite group is included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID};
+    /// #
+    /// let mut catalog = Catalog::new();
+    /// let first = CourseID::new("TEST", 1100);
+    /// let second = CourseID::new("TEST", 1200);
+    /// let third = CourseID::new("TEST", 1300);
+    ///
+    /// catalog.emplace_course(&first);
+    /// catalog.emplace_course(&second);
+    /// catalog.emplace_course(&third);
+    /// catalog.get_course_mut(&second).unwrap().add_prereq(&first);
+    /// catalog.get_course_mut(&third).unwrap().add_prereq(&second);
+    ///
+    /// let prereqs = catalog.transitive_prereqs(&third);
+    ///
+    /// assert_eq!(prereqs.len(), 2);
+    /// assert!(prereqs.contains(&first));
+    /// assert!(prereqs.contains(&second));
+    /// ```
+    pub fn transitive_prereqs(&self, coid: &CourseID) -> HashSet<CourseID> {
+        let tree = self
+            .get_course_tree(coid)
+            .map(|(tree, _)| tree)
+            .unwrap_or_default();
+
+        tree.iter()
+            .map(|course| course.get_id())
+            .filter(|prereq| prereq != coid)
+            .collect()
+    }
+
+    /// Returns every course which transitively lists the given course as a
+    /// prerequisite, following each course's post_options, not including
+    /// the course itself.  This is everything taking the course eventually
+    /// opens up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID};
+    /// #
+    /// let first = CourseID::new("TEST", 1100);
+    /// let second = CourseID::new("TEST", 1200);
+    /// let third = CourseID::new("TEST", 1300);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&first).build());
+    /// catalog.add_course(CourseBuilder::new(&second).prereq_group(vec![first.clone()]).build());
+    /// catalog.add_course(CourseBuilder::new(&third).prereq_group(vec![second.clone()]).build());
+    ///
+    /// let reachable = catalog.reachable_from(&first);
+    ///
+    /// assert_eq!(reachable.len(), 2);
+    /// assert!(reachable.contains(&second));
+    /// assert!(reachable.contains(&third));
+    /// assert!(catalog.reachable_from(&third).is_empty());
+    /// ```
+    pub fn reachable_from(&self, coid: &CourseID) -> HashSet<CourseID> {
+        let mut reachable = HashSet::new();
+        let mut queue: VecDeque<&CourseID> = VecDeque::new();
+        queue.push_back(coid);
+
+        while let Some(current) = queue.pop_front() {
+            let course = match self.get_course(current) {
+                Some(course) => course,
+                None => continue,
+            };
+
+            for post in course.post_options() {
+                if post != coid && reachable.insert(post.clone()) {
+                    queue.push_back(post);
+                }
+            }
+        }
+
+        reachable
+    }
+
+    /// Estimates the number of credits needed to take the given course,
+    /// summing the minimum credits of the course and all of its transitive
+    /// prerequisites.  Every option of each prerequisite group is counted,
+    /// and placeholder courses without credits contribute nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, CreditRange};
+    /// #
+    /// let first = CourseID::new("TEST", 1100);
+    /// let second = CourseID::new("TEST", 1200);
+    /// let third = CourseID::new("TEST", 1300);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&first).credits(CreditRange::fixed(4)).build());
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&second)
+    ///         .credits(CreditRange::fixed(3))
+    ///         .prereq_group(vec![first])
+    ///         .build(),
+    /// );
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&third)
+    ///         .credits(CreditRange::new(1, 4))
+    ///         .prereq_group(vec![second])
+    ///         .build(),
+    /// );
+    ///
+    /// assert_eq!(catalog.credits_for_closure(&third), 8);
+    /// ```
+    pub fn credits_for_closure(&self, coid: &CourseID) -> u32 {
+        let tree = self
+            .get_course_tree(coid)
+            .map(|(tree, _)| tree)
+            .unwrap_or_default();
+
+        tree.iter()
+            .map(|course| u32::from(course.credits().min()))
+            .sum()
+    }
+
+    /// Estimates the minimum number of terms needed to complete the given
+    /// course when starting in `start`.  Each course is placed in the
+    /// earliest term it is offered in after all of its prerequisites, using
+    /// whichever option of each prerequisite group finishes first.  Every
+    /// term is counted, including summer terms.
+    ///
+    /// Returns `None` if the course or one of its prerequisites is missing
+    /// from the catalog, or if its prerequisites form a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, SemTime};
+    /// #
+    /// let fall_only = CourseID::new("TEST", 1100);
+    /// let spring_only = CourseID::new("TEST", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&fall_only).offered("Fall").build());
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&spring_only)
+    ///         .offered("Spring")
+    ///         .prereq_group(vec![fall_only.clone()])
+    ///         .build(),
+    /// );
+    ///
+    /// // Fall 2024, Spring 2025
+    /// assert_eq!(catalog.min_terms_to_complete(&spring_only, SemTime::Fall(2024)), Some(2));
+    ///
+    /// // Spring 2024, Summer 2024, Fall 2024, Spring 2025
+    /// assert_eq!(catalog.min_terms_to_complete(&spring_only, SemTime::Spring(2024)), Some(4));
+    /// ```
+    pub fn min_terms_to_complete(&self, coid: &CourseID, start: SemTime) -> Option<usize> {
+        let finish =
+            self.earliest_completion(coid, &start, &mut HashMap::new(), &mut HashSet::new())?;
+
+        let mut terms = 1;
+        let mut time = start;
+        while time < finish {
+            time = time.next();
+            terms += 1;
+        }
+
+        Some(terms)
+    }
+
+    /// Returns the earliest term the course can be taken in, memoizing
+    /// results in `earliest` and tracking the current path in `visiting` to
+    /// detect cycles.
+    fn earliest_completion(
+        &self,
+        coid: &CourseID,
+        start: &SemTime,
+        earliest: &mut HashMap<CourseID, Option<SemTime>>,
+        visiting: &mut HashSet<CourseID>,
+    ) -> Option<SemTime> {
+        if let Some(time) = earliest.get(coid) {
+            return time.clone();
+        }
+
+        let course = self.get_course(coid)?;
+
+        if !visiting.insert(coid.clone()) {
+            return None;
+        }
+
+        let mut ready = Some(start.clone());
+        for prereq_set in course.prereq_sets() {
+            let mut set_ready = None;
+            for prereq in prereq_set {
+                if let Some(time) = self.earliest_completion(prereq, start, earliest, visiting) {
+                    let after = time.next();
+                    if set_ready.as_ref().is_none_or(|best| &after < best) {
+                        set_ready = Some(after);
+                    }
+                }
+            }
+
+            ready = match (ready, set_ready) {
+                (Some(ready), Some(set_ready)) => Some(ready.max(set_ready)),
+                _ => None,
+            };
+        }
+
+        let offered = course.offered_seasons();
+        let time = ready.map(|mut time| {
+            while !offered.contains(&time.season()) {
+                time = time.next();
+            }
+            time
+        });
+
+        visiting.remove(coid);
+        earliest.insert(coid.clone(), time.clone());
+        time
+    }
+
+    /// Returns every course which can never be taken, no matter how many
+    /// terms are available after `start`, sorted by ID.  A course is
+    /// infeasible if its prerequisites form a cycle, if a requisite group
+    /// has no option in the catalog, or if the seasons its requisites are
+    /// offered in rule out every ordering, such as a Fall-only course whose
+    /// corequisite is only offered in the Spring.  Courses requiring an
+    /// infeasible course with no alternative are infeasible as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, SemTime};
+    /// #
+    /// let lab = CourseID::new("CHEM", 1110);
+    /// let lecture = CourseID::new("CHEM", 1100);
+    /// let organic = CourseID::new("CHEM", 2250);
+    /// let calc = CourseID::new("MATH", 1010);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&lab).offered("Spring").build());
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&lecture)
+    ///         .offered("Fall")
+    ///         .coreq_group(vec![lab.clone()])
+    ///         .build(),
+    /// );
+    /// catalog.add_course(CourseBuilder::new(&organic).prereq_group(vec![lecture.clone()]).build());
+    /// catalog.add_course(CourseBuilder::new(&calc).offered("Fall").build());
+    ///
+    /// assert_eq!(
+    ///     catalog.infeasible_courses(SemTime::Fall(2024)),
+    ///     vec![lecture, organic]
+    /// );
+    /// ```
+    ///
+    /// Courses on a prerequisite cycle are infeasible unless one of them has
+    /// an alternative prerequisite outside the cycle:
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, SemTime};
+    /// #
+    /// let first = CourseID::new("LOOP", 1100);
+    /// let second = CourseID::new("LOOP", 1200);
+    /// let after = CourseID::new("LOOP", 2100);
+    /// let entry = CourseID::new("OPEN", 1000);
+    /// let open_first = CourseID::new("OPEN", 1100);
+    /// let open_second = CourseID::new("OPEN", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&first).prereq_group(vec![second.clone()]).build());
+    /// catalog.add_course(CourseBuilder::new(&second).prereq_group(vec![first.clone()]).build());
+    /// catalog.add_course(CourseBuilder::new(&after).prereq_group(vec![second.clone()]).build());
+    ///
+    /// catalog.add_course(CourseBuilder::new(&entry).build());
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&open_first)
+    ///         .prereq_group(vec![open_second.clone(), entry.clone()])
+    ///         .build(),
+    /// );
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&open_second)
+    ///         .prereq_group(vec![open_first.clone()])
+    ///         .build(),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     catalog.infeasible_courses(SemTime::Fall(2024)),
+    ///     vec![first, second, after]
+    /// );
+    /// ```
+    pub fn infeasible_courses(&self, start: SemTime) -> Vec<CourseID> {
+        let mut coids: Vec<&CourseID> = self.courses.keys().collect();
+        coids.sort();
+
+        let offered: HashMap<&CourseID, HashSet<Season>> = self
+            .courses
+            .iter()
+            .map(|(coid, course)| (coid, course.offered_seasons()))
+            .collect();
+
+        let mut infeasible: HashSet<&CourseID> = HashSet::new();
+
+        'grounding: loop {
+            // Courses whose prerequisites can't be ordered, such as those on
+            // a cycle with no alternative, never leave the stack of pending
+            // requisites and are infeasible without looking at seasons
+            let orderable = self.orderable_courses(&infeasible);
+            infeasible.extend(coids.iter().filter(|coid| !orderable.contains(**coid)));
+
+            let mut earliest: HashMap<&CourseID, SemTime> = coids
+                .iter()
+                .filter(|coid| !infeasible.contains(**coid))
+                .map(|coid| (*coid, start.clone()))
+                .collect();
+
+            let mut changed = true;
+            while changed {
+                changed = false;
+
+                for coid in &coids {
+                    if infeasible.contains(coid) {
+                        continue;
+                    }
+                    let course = &self.courses[*coid];
+                    let feasible = |option: &CourseID| earliest.contains_key(option);
+
+                    // Lower bound from prerequisites, using the earliest option
+                    let mut ready = Some(earliest[*coid].clone());
+                    for group in course.prereq_sets() {
+                        let group_ready = group
+                            .iter()
+                            .filter(|option| feasible(option))
+                            .map(|option| earliest[option].next())
+                            .min();
+                        ready = match (ready, group_ready) {
+                            (Some(ready), Some(group_ready)) => Some(ready.max(group_ready)),
+                            _ => None,
+                        };
+                    }
+
+                    let all_coreqs_possible = course
+                        .coreq_sets()
+                        .iter()
+                        .all(|group| group.iter().any(&feasible));
+
+                    // Find the first term the course and one option of each
+                    // corequisite group could share.  Once every option is
+                    // available, a year of terms covers every season.
+                    let time = ready.filter(|_| all_coreqs_possible).and_then(|mut time| {
+                        let latest = course
+                            .coreq_sets()
+                            .iter()
+                            .flatten()
+                            .filter_map(|option| earliest.get(option))
+                            .fold(time.clone(), |latest, option| latest.max(option.clone()));
+
+                        loop {
+                            if latest.terms_until(&time, true) > 3 {
+                                return None;
+                            }
+
+                            let season = time.season();
+                            let fits = offered[*coid].contains(&season)
+                                && course.coreq_sets().iter().all(|group| {
+                                    group.iter().any(|option| {
+                                        feasible(option)
+                                            && earliest[option] <= time
+                                            && offered[option].contains(&season)
+                                    })
+                                });
+                            if fits {
+                                return Some(time);
+                            }
+                            time = time.next();
+                        }
+                    });
+
+                    match time {
+                        Some(time) => {
+                            if time != earliest[*coid] {
+                                earliest.insert(coid, time);
+                                changed = true;
+                            }
+                        }
+                        None => {
+                            // Removing a course may leave another cycle
+                            // without a way in, so the orderable courses are
+                            // found again before continuing
+                            infeasible.insert(coid);
+                            continue 'grounding;
+                        }
+                    }
+                }
+            }
+
+            break;
+        }
+
+        let mut infeasible: Vec<CourseID> = infeasible.into_iter().cloned().collect();
+        infeasible.sort();
+        infeasible
+    }
+
+    /// Returns every course which is not in `excluded` and whose
+    /// prerequisites can be taken in some order, ignoring seasons and
+    /// corequisites.  Each course is released once every one of its
+    /// prerequisite groups has a released option, so the courses on a
+    /// prerequisite cycle with no alternative are never released.
+    fn orderable_courses(&self, excluded: &HashSet<&CourseID>) -> HashSet<&CourseID> {
+        let mut pending: HashMap<&CourseID, usize> = HashMap::new();
+        let mut dependents: HashMap<&CourseID, Vec<(&CourseID, usize)>> = HashMap::new();
+        let mut released: Vec<&CourseID> = Vec::new();
+
+        for (coid, course) in &self.courses {
+            if excluded.contains(coid) {
+                continue;
+            }
+
+            let groups = course.prereq_sets();
+            pending.insert(coid, groups.len());
+            if groups.is_empty() {
+                released.push(coid);
+            }
+            for (index, group) in groups.iter().enumerate() {
+                for option in group {
+                    dependents.entry(option).or_default().push((coid, index));
+                }
+            }
+        }
+
+        let mut orderable = HashSet::new();
+        let mut met_groups: HashSet<(&CourseID, usize)> = HashSet::new();
+        while let Some(coid) = released.pop() {
+            orderable.insert(coid);
+
+            for &(dependent, index) in dependents.get(coid).into_iter().flatten() {
+                if met_groups.insert((dependent, index)) {
+                    let remaining = pending.get_mut(dependent).unwrap();
+                    *remaining -= 1;
+                    if *remaining == 0 {
+                        released.push(dependent);
+                    }
+                }
+            }
+        }
+
+        orderable
+    }
+
+    /// Returns every prerequisite relationship in the catalog as a
+    /// `(prerequisite, course)` pair, sorted and without duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID};
+    /// #
+    /// let mut catalog = Catalog::new();
+    /// let first = CourseID::new("TEST", 1100);
+    /// let second = CourseID::new("TEST", 1200);
+    /// let third = CourseID::new("TEST", 1300);
+    ///
+    /// catalog.emplace_course(&first);
+    /// catalog.emplace_course(&second);
+    /// catalog.emplace_course(&third);
+    /// catalog.get_course_mut(&third).unwrap().add_prereq(&second);
+    /// catalog.get_course_mut(&second).unwrap().add_prereq(&first);
+    ///
+    /// assert_eq!(
+    ///     catalog.prereq_edges(),
+    ///     vec![(first, second.clone()), (second, third)]
+    /// );
+    /// ```
+    pub fn prereq_edges(&self) -> Vec<(CourseID, CourseID)> {
+        let mut edges: Vec<(CourseID, CourseID)> = self
+            .courses
+            .values()
+            .flat_map(|course| {
+                course
+                    .prereqs
+                    .iter()
+                    .flatten()
+                    .map(move |prereq| (prereq.clone(), course.get_id()))
+            })
+            .collect();
+
+        edges.sort();
+        edges.dedup();
+        edges
+    }
+
+    /// Returns every course offered in the given season, sorted by ID.
+    /// Courses with an empty `offered` field are offered in every season.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, Season};
+    /// #
+    /// let fall_only = CourseID::new("TEST", 1100);
+    /// let always = CourseID::new("TEST", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&fall_only).offered("Fall").build());
+    /// catalog.add_course(CourseBuilder::new(&always).build());
+    ///
+    /// let spring: Vec<CourseID> = catalog
+    ///     .courses_offered_in(Season::Spring)
+    ///     .iter()
+    ///     .map(|course| course.get_id())
+    ///     .collect();
+    ///
+    /// assert_eq!(spring, vec![always]);
+    /// assert_eq!(catalog.courses_offered_in(Season::Fall).len(), 2);
+    /// ```
+    pub fn courses_offered_in(&self, season: Season) -> Vec<&Course> {
+        let mut courses: Vec<&Course> = self
+            .courses
+            .values()
+            .filter(|course| course.offered_seasons().contains(&season))
+            .collect();
+
+        courses.sort_by(|a, b| a.coid.cmp(&b.coid));
+        courses
+    }
+
+    /// Returns the courses in the same subject as the given ID whose codes
+    /// are within `max_distance` of its code, closest first, with ties
+    /// broken by code.  This is useful for suggesting what a user meant
+    /// after looking up a course which does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID};
+    /// #
+    /// let mut catalog = Catalog::new();
+    /// for (subj, code) in &[("CS", 1100), ("CS", 1200), ("CS", 2500), ("MATH", 1010)] {
+    ///     catalog.add_course(CourseBuilder::new(&CourseID::new(subj, *code)).build());
+    /// }
+    ///
+    /// let typo = CourseID::new("CS", 1010);
+    /// assert!(catalog.get_course(&typo).is_none());
+    ///
+    /// let suggestions: Vec<CourseID> = catalog
+    ///     .closest(&typo, 100)
+    ///     .iter()
+    ///     .map(|course| course.get_id())
+    ///     .collect();
+    /// assert_eq!(suggestions, vec![CourseID::new("CS", 1100)]);
+    ///
+    /// assert_eq!(catalog.closest(&typo, 200).len(), 2);
+    /// ```
+    pub fn closest(&self, coid: &CourseID, max_distance: u16) -> Vec<&Course> {
+        let distance =
+            |course: &Course| (i32::from(course.coid.code()) - i32::from(coid.code())).abs();
+
+        let mut courses: Vec<&Course> = self
+            .courses
+            .values()
+            .filter(|course| course.coid.subj() == coid.subj())
+            .filter(|course| distance(course) <= i32::from(max_distance))
+            .collect();
+
+        courses.sort_by_key(|course| (distance(course), course.coid.code()));
+        courses
+    }
+
+    /// Returns every course carrying the given tag, sorted by ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID};
+    /// #
+    /// let philosophy = CourseID::new("PHIL", 1100);
+    /// let economics = CourseID::new("ECON", 1200);
+    /// let calculus = CourseID::new("MATH", 1010);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&philosophy).tag("HASS").build());
+    /// catalog.add_course(CourseBuilder::new(&economics).tag("HASS").tag("Writing Intensive").build());
+    /// catalog.add_course(CourseBuilder::new(&calculus).build());
+    ///
+    /// let hass: Vec<CourseID> = catalog
+    ///     .courses_with_tag("HASS")
+    ///     .iter()
+    ///     .map(|course| course.get_id())
+    ///     .collect();
+    ///
+    /// assert_eq!(hass, vec![economics, philosophy]);
+    /// assert!(catalog.courses_with_tag("Lab").is_empty());
+    /// ```
+    pub fn courses_with_tag(&self, tag: &str) -> Vec<&Course> {
+        let mut courses: Vec<&Course> = self
+            .courses
+            .values()
+            .filter(|course| course.tags.contains(tag))
+            .collect();
+
+        courses.sort_by(|a, b| a.coid.cmp(&b.coid));
+        courses
+    }
+
+    /// Removes every placeholder course, such as those created for
+    /// prerequisites which were never defined, and returns their IDs in
+    /// sorted order.  Courses which refer to a removed placeholder keep
+    /// doing so, so `validate` still reports the undefined requisite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CatalogWarning, CourseBuilder, CourseID};
+    /// #
+    /// let defined = CourseID::new("TEST", 1100);
+    /// let undefined = CourseID::new("TEST", 1000);
+    /// let course = CourseID::new("TEST", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&defined).build());
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&course)
+    ///         .prereq_group(vec![defined.clone()])
+    ///         .prereq_group(vec![undefined.clone()])
+    ///         .build(),
+    /// );
+    /// assert!(catalog.get_course(&undefined).is_some());
+    ///
+    /// assert_eq!(catalog.prune_stubs(), vec![undefined.clone()]);
+    ///
+    /// assert!(catalog.get_course(&undefined).is_none());
+    /// assert!(catalog.get_course(&defined).is_some());
+    /// assert!(catalog.get_course(&course).is_some());
+    /// assert!(catalog.validate().contains(&CatalogWarning::UndefinedPrereq {
+    ///     course,
+    ///     prereq: undefined,
+    /// }));
+    /// ```
+    pub fn prune_stubs(&mut self) -> Vec<CourseID> {
+        let mut stubs: Vec<CourseID> = self
+            .courses
+            .values()
+            .filter(|course| !course.complete)
+            .map(|course| course.get_id())
+            .collect();
+        stubs.sort();
+
+        for coid in &stubs {
+            self.courses.remove(coid);
+        }
+
+        stubs
+    }
+
+    /// Returns every complete course which is nonetheless missing its name,
+    /// its description, or any credits, which usually means its data was
+    /// only partly entered.  Placeholder courses are not reported, since
+    /// they are known to be incomplete.  Courses are sorted by ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, CreditRange};
+    /// #
+    /// let unnamed = CourseID::new("TEST", 1100);
+    /// let filled = CourseID::new("TEST", 1200);
+    /// let placeholder = CourseID::new("TEST", 1300);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&unnamed)
+    ///         .description("Covers the basics.")
+    ///         .credits(CreditRange::fixed(4))
+    ///         .build(),
+    /// );
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&filled)
+    ///         .name("Intermediate Testing")
+    ///         .description("Covers the rest.")
+    ///         .credits(CreditRange::fixed(4))
+    ///         .build(),
+    /// );
+    /// catalog.get_course_or_stub(&placeholder);
+    ///
+    /// let missing: Vec<CourseID> = catalog
+    ///     .courses_missing_metadata()
+    ///     .iter()
+    ///     .map(|course| course.get_id())
+    ///     .collect();
+    ///
+    /// assert_eq!(missing, vec![unnamed]);
+    /// ```
+    pub fn courses_missing_metadata(&self) -> Vec<&Course> {
+        let mut courses: Vec<&Course> = self
+            .courses
+            .values()
+            .filter(|course| course.complete)
+            .filter(|course| {
+                course.name.is_empty() || course.description.is_empty() || course.credits.max() == 0
+            })
+            .collect();
+
+        courses.sort_by(|a, b| a.coid.cmp(&b.coid));
+        courses
+    }
+
+    /// Checks the whole catalog for consistency problems, such as
+    /// prerequisite cycles, courses which require themselves, requirements
+    /// on undefined courses, and complete courses without a name.
+    ///
+    /// Warnings are returned in course order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CatalogWarning, CourseID};
+    /// #
+    /// let mut catalog = Catalog::new();
+    /// let coid = CourseID::new("TEST", 1100);
+    /// let undefined = CourseID::new("MISS", 1000);
+    ///
+    /// catalog.emplace_course(&coid);
+    /// let course = catalog.get_course_mut(&coid).unwrap();
+    /// course.add_prereq(&coid);
+    /// course.add_prereq(&undefined);
+    ///
+    /// let warnings = catalog.validate();
+    ///
+    /// assert!(warnings.contains(&CatalogWarning::SelfPrereq(coid.clone())));
+    /// assert!(warnings.contains(&CatalogWarning::UndefinedPrereq {
+    ///     course: coid,
+    ///     prereq: undefined,
+    /// }));
+    /// ```
+    pub fn validate(&self) -> Vec<CatalogWarning> {
+        let mut warnings = Vec::new();
+
+        let mut coids: Vec<&CourseID> = self.courses.keys().collect();
+        coids.sort();
+
+        for coid in &coids {
+            let course = &self.courses[*coid];
+
+            if course.complete && course.name.is_empty() {
+                warnings.push(CatalogWarning::MissingName((*coid).clone()));
+            }
+
+            let mut reqs: Vec<&CourseID> = course
+                .prereqs
+                .iter()
+                .chain(course.coreqs.iter())
+                .flatten()
+                .collect();
+            reqs.sort();
+            reqs.dedup();
+
+            let dropped_self_reference = self.self_references.contains(*coid);
+            if dropped_self_reference {
+                warnings.push(CatalogWarning::SelfPrereq((*coid).clone()));
+            }
+
+            for req in reqs {
+                if req == *coid {
+                    if !dropped_self_reference {
+                        warnings.push(CatalogWarning::SelfPrereq(req.clone()));
+                    }
+                    continue;
+                }
+
+                match self.get_course(req) {
+                    Some(req_course) if req_course.complete => (),
+                    _ => warnings.push(CatalogWarning::UndefinedPrereq {
+                        course: (*coid).clone(),
+                        prereq: req.clone(),
+                    }),
+                }
+            }
+        }
+
+        let mut finished = HashSet::new();
+        let mut in_cycle = HashSet::new();
+        for coid in &coids {
+            self.find_cycles(coid, &mut Vec::new(), &mut finished, &mut in_cycle);
+        }
+
+        let mut cycle_coids: Vec<CourseID> = in_cycle.into_iter().collect();
+        cycle_coids.sort();
+        warnings.extend(cycle_coids.into_iter().map(CatalogWarning::PrereqCycle));
+
+        warnings
+    }
+
+    /// Depth-first search over the prerequisite graph which records every
+    /// course on a cycle of length two or more in `in_cycle`.
+    fn find_cycles(
+        &self,
+        coid: &CourseID,
+        path: &mut Vec<CourseID>,
+        finished: &mut HashSet<CourseID>,
+        in_cycle: &mut HashSet<CourseID>,
+    ) {
+        if finished.contains(coid) {
+            return;
+        }
+
+        if let Some(pos) = path.iter().position(|visited| visited == coid) {
+            in_cycle.extend(path[pos..].iter().cloned());
+            return;
+        }
+
+        if let Some(course) = self.get_course(coid) {
+            path.push(coid.clone());
+            for prereq in course.prereqs.iter().flatten() {
+                if prereq != coid {
+                    self.find_cycles(prereq, path, finished, in_cycle);
+                }
+            }
+            path.pop();
+        }
+
+        finished.insert(coid.clone());
+    }
+
+    /// Replaces the prerequisite `old` with `new` in every prerequisite
+    /// group of `course`, carrying over any minimum grade, and moves `course`
+    /// from the post_options of `old` to those of `new`.  A placeholder is
+    /// added for `new` if it is not in the catalog.
+    ///
+    /// Returns if anything was changed.  Nothing is changed if `course` is
+    /// not in the catalog, does not require `old`, or if `new` is `course`
+    /// itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID};
+    /// #
+    /// let old = CourseID::new("CSCI", 1100);
+    /// let new = CourseID::new("CSCI", 1010);
+    /// let course = CourseID::new("CSCI", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&old).build());
+    /// catalog.add_course(CourseBuilder::new(&new).build());
+    /// catalog.add_course(CourseBuilder::new(&course).prereq_group(vec![old.clone()]).build());
+    ///
+    /// assert!(catalog.replace_prereq(&course, &old, &new));
+    ///
+    /// assert!(catalog.get_course(&course).unwrap().prereq_sets()[0].contains(&new));
+    /// assert!(!catalog.get_course(&old).unwrap().post_options().contains(&course));
+    /// assert!(catalog.get_course(&new).unwrap().post_options().contains(&course));
+    ///
+    /// // The old prerequisite is already gone
+    /// assert!(!catalog.replace_prereq(&course, &old, &new));
+    /// ```
+    pub fn replace_prereq(&mut self, course: &CourseID, old: &CourseID, new: &CourseID) -> bool {
+        if new == course {
+            return false;
+        }
+
+        let dependent = match self.courses.get_mut(course) {
+            Some(dependent) => dependent,
+            None => return false,
+        };
+
+        let mut changed = false;
+        for group in &mut dependent.prereqs {
+            if group.remove(old) {
+                group.insert(new.clone());
+                changed = true;
+            }
+        }
+
+        if !changed {
+            return false;
+        }
+
+        if let Some(grade) = dependent.prereq_grades.remove(old) {
+            dependent.prereq_grades.insert(new.clone(), grade);
+        }
+
+        if let Some(old_course) = self.courses.get_mut(old) {
+            old_course.post_options.remove(course);
+        }
+
+        self.courses
+            .entry(new.clone())
+            .or_insert_with(|| Course::placeholder(new))
+            .add_postoption(course);
+
+        true
+    }
+
+    /// Renames every course with the subject `old` to the subject `new`,
+    /// including every prerequisite, corequisite, and post option which
+    /// refers to such a course.  Returns the number of courses which were
+    /// changed.
+    ///
+    /// If a renamed course collides with a course already using the new
+    /// subject, the existing course is kept and the renamed course's
+    /// post_options are merged into it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID};
+    /// #
+    /// let mut catalog = Catalog::new();
+    /// let intro = CourseID::new("SYST", 1100);
+    /// let advanced = CourseID::new("TEST", 2100);
+    ///
+    /// catalog.emplace_course(&intro);
+    /// catalog.emplace_course(&advanced);
+    /// catalog.get_course_mut(&advanced).unwrap().add_prereq(&intro);
+    ///
+    /// assert_eq!(catalog.rename_subject("SYST", "SYSE"), 2);
+    ///
+    /// let renamed = CourseID::new("SYSE", 1100);
+    /// assert!(catalog.get_course(&intro).is_none());
+    /// assert_eq!(catalog.get_course(&renamed).unwrap().get_id(), renamed);
+    /// assert!(catalog.get_course(&advanced).unwrap().prereq_sets()[0].contains(&renamed));
+    /// ```
+    pub fn rename_subject(&mut self, old: &str, new: &str) -> usize {
+        if old == new {
+            return 0;
+        }
+
+        let mut touched = 0;
+        let mut renamed = Vec::new();
+
+        for (coid, course) in self.courses.iter_mut() {
+            if course.rename_subject(old, new) {
+                touched += 1;
+            }
+            if coid.subj() == old {
+                renamed.push(coid.clone());
+            }
+        }
+
+        self.self_references = self
+            .self_references
+            .iter()
+            .map(|coid| {
+                if coid.subj() == old {
+                    CourseID::new(new, coid.code())
+                } else {
+                    coid.clone()
+                }
+            })
+            .collect();
+
+        renamed.sort();
+        for old_coid in renamed {
+            let course = self.courses.remove(&old_coid).unwrap();
+            match self.courses.get_mut(&course.coid) {
+                Some(existing) => existing.post_options.extend(course.post_options),
+                None => {
+                    self.courses.insert(course.get_id(), course);
+                }
+            }
+        }
+
+        touched
+    }
+
+    /// Adds every course from another catalog into this one.  Courses from
+    /// `other` overwrite existing courses as in `add_course`, except that
+    /// placeholder courses never overwrite existing courses.  Post options
+    /// from both catalogs are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID};
+    /// #
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let calc = CourseID::new("MATH", 1010);
+    ///
+    /// let mut csci = Catalog::new();
+    /// csci.add_course(CourseBuilder::new(&intro).prereq_group(vec![calc.clone()]).build());
+    ///
+    /// let mut math = Catalog::new();
+    /// math.add_course(CourseBuilder::new(&calc).name("Calculus I").build());
+    ///
+    /// csci.merge(math);
+    ///
+    /// assert_eq!(format!("{}", csci.get_course(&calc).unwrap()), "MATH 1010: Calculus I");
+    /// assert!(csci.get_course(&intro).is_some());
+    /// ```
+    pub fn merge(&mut self, other: Catalog) {
+        let mut courses: Vec<Course> = other.courses.into_values().collect();
+        courses.sort_by(|a, b| a.coid.cmp(&b.coid));
+
+        for course in courses {
+            let coid = course.get_id();
+            let post_options = course.post_options.clone();
+
+            let is_placeholder = !course.complete;
+            if !(is_placeholder && self.courses.contains_key(&coid)) {
+                self.add_course(course);
+            }
+
+            if let Some(existing) = self.get_course_mut(&coid) {
+                existing.post_options.extend(post_options);
+            }
+        }
+
+        self.equivalencies.extend(other.equivalencies);
+        self.self_references.extend(other.self_references);
+    }
+
+    /// Records that a course from another institution, such as one brought
+    /// in as transfer credit, is equivalent to a local course.  Prerequisite
+    /// checks treat the external course as if it were the local one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let transfer = CourseID::new("XFER", 1000);
+    /// let calc = CourseID::new("MATH", 1010);
+    /// let calc2 = CourseID::new("MATH", 1020);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&calc).build());
+    /// catalog.add_course(CourseBuilder::new(&calc2).prereq_group(vec![calc.clone()]).build());
+    /// catalog.add_equivalency(transfer.clone(), calc.clone());
+    ///
+    /// assert_eq!(catalog.resolve_equivalent(&transfer), calc);
+    /// assert_eq!(catalog.resolve_equivalent(&calc2), calc2);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![transfer.clone()]));
+    /// schedule.add_semester(Semester::new(SemTime::Spring(2020)));
+    ///
+    /// assert!(!schedule.contains(&calc));
+    /// assert!(schedule.contains_equivalent(&calc, &catalog));
+    ///
+    /// let config = GenerationConfig::default();
+    /// assert_eq!(schedule.explain_placement(&calc2, &SemTime::Spring(2020), &catalog, &config), None);
+    ///
+    /// // The local course is not added again when generating a schedule
+    /// let schedules = Schedule::add_course_to_schedule_with_config(&calc2, &schedule, &catalog, &config);
+    /// assert_eq!(schedules.len(), 1);
+    /// assert!(!schedules[0].contains(&calc));
+    /// ```
+    pub fn add_equivalency(&mut self, external: CourseID, local: CourseID) {
+        self.equivalencies.insert(external, local);
+    }
+
+    /// Returns the local course equivalent to the given course, or the
+    /// course itself if it has no recorded equivalent.
+    pub fn resolve_equivalent(&self, coid: &CourseID) -> CourseID {
+        self.equivalencies
+            .get(coid)
+            .cloned()
+            .unwrap_or_else(|| coid.clone())
+    }
+
+    /// Returns if any equivalencies or substitute courses have been recorded.
+    pub(crate) fn has_equivalencies(&self) -> bool {
+        !self.equivalencies.is_empty() || self.has_substitutes
+    }
+
+    /// Returns if taking `taken` counts as taking `required`, either because
+    /// they are equivalent or because `taken` substitutes for `required`.
+    pub(crate) fn is_equivalent(&self, taken: &CourseID, required: &CourseID) -> bool {
+        taken == required
+            || self.equivalencies.get(taken) == Some(required)
+            || (self.has_substitutes
+                && self
+                    .get_course(taken)
+                    .and_then(|course| course.substitutes_for.as_ref())
+                    == Some(required))
+    }
+
+    /// Collects everything about a course needed to describe it: the course
+    /// itself, its prerequisites, the courses which require it, and how many
+    /// courses it transitively requires.  Returns `None` if the course is
+    /// not in the catalog.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID};
+    /// #
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let data = CourseID::new("CSCI", 1200);
+    /// let algo = CourseID::new("CSCI", 2300);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&intro).build());
+    /// catalog.add_course(CourseBuilder::new(&data).prereq_group(vec![intro.clone()]).build());
+    /// catalog.add_course(CourseBuilder::new(&algo).prereq_group(vec![data.clone()]).build());
+    ///
+    /// let description = catalog.describe_course(&data).unwrap();
+    ///
+    /// assert_eq!(description.course.get_id(), data);
+    /// assert_eq!(description.prereq_groups.len(), 1);
+    /// assert_eq!(description.prereq_groups[0][0].get_id(), intro);
+    /// assert_eq!(description.dependents.len(), 1);
+    /// assert_eq!(description.dependents[0].get_id(), algo);
+    /// assert_eq!(description.transitive_prereq_count, 1);
+    ///
+    /// assert!(catalog.describe_course(&CourseID::new("MISS", 1000)).is_none());
+    /// ```
+    pub fn describe_course(&self, coid: &CourseID) -> Option<CourseDescription<'_>> {
+        let course = self.get_course(coid)?;
+
+        let prereq_groups = course
+            .prereqs
+            .iter()
+            .map(|group| {
+                let mut courses: Vec<&Course> = group
+                    .iter()
+                    .filter_map(|prereq| self.get_course(prereq))
+                    .collect();
+                courses.sort_by(|a, b| a.coid.cmp(&b.coid));
+                courses
+            })
+            .collect();
+
+        let mut dependents: Vec<&Course> = self
+            .courses
+            .values()
+            .filter(|other| other.prereqs.iter().any(|group| group.contains(coid)))
+            .collect();
+        dependents.sort_by(|a, b| a.coid.cmp(&b.coid));
+
+        Some(CourseDescription {
+            course,
+            prereq_groups,
+            dependents,
+            transitive_prereq_count: self.transitive_prereqs(coid).len(),
+        })
+    }
+
+    /// Returns the course's prerequisites as a list of groups, every one of
+    /// which must be satisfied by taking any one of its alternatives.
+    /// Returns `None` if the course is not in the catalog.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID};
+    /// #
+    /// let calc = CourseID::new("MATH", 1010);
+    /// let honors_calc = CourseID::new("MATH", 1019);
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let data = CourseID::new("CSCI", 2100);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&data)
+    ///         .prereq_group(vec![intro.clone()])
+    ///         .prereq_group(vec![honors_calc.clone(), calc.clone()])
+    ///         .build(),
+    /// );
+    ///
+    /// let logic = catalog.prereq_logic(&data).unwrap();
+    ///
+    /// assert_eq!(logic.len(), 2);
+    /// assert!(logic[0].is_single());
+    /// assert_eq!(logic[0].alternatives(), &[intro][..]);
+    /// assert!(!logic[1].is_single());
+    /// assert_eq!(logic[1].alternatives(), &[calc, honors_calc][..]);
+    ///
+    /// assert_eq!(catalog.prereq_logic(&CourseID::new("TEST", 9999)), None);
+    /// ```
+    pub fn prereq_logic(&self, coid: &CourseID) -> Option<PrereqLogic> {
+        let course = self.get_course(coid)?;
+
+        Some(
+            course
+                .prereq_sets()
+                .iter()
+                .map(|group| {
+                    let mut alternatives: Vec<CourseID> = group.iter().cloned().collect();
+                    alternatives.sort();
+                    PrereqGroup { alternatives }
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns, for each prerequisite group of the course, every acceptable
+    /// alternative which exists in the catalog.  Returns an empty vector if
+    /// the course is not in the catalog.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID};
+    /// # use std::collections::HashSet;
+    /// #
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let alt_intro = CourseID::new("CSCI", 1010);
+    /// let data = CourseID::new("CSCI", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&intro).build());
+    /// catalog.add_course(CourseBuilder::new(&alt_intro).build());
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&data)
+    ///         .prereq_group(vec![intro.clone(), alt_intro.clone()])
+    ///         .build(),
+    /// );
+    ///
+    /// let expected: HashSet<CourseID> = vec![intro, alt_intro].into_iter().collect();
+    /// assert_eq!(catalog.find_alternative_prereqs(&data), vec![expected]);
+    /// ```
+    pub fn find_alternative_prereqs(&self, coid: &CourseID) -> Vec<HashSet<CourseID>> {
+        let course = match self.get_course(coid) {
+            Some(course) => course,
+            None => return Vec::new(),
+        };
+
+        course
+            .prereqs
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .filter(|prereq| self.courses.contains_key(prereq))
+                    .cloned()
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the given course and every course it transitively requires
+    /// in an order they can be taken in, each course appearing after its
+    /// prerequisites and the given course appearing last.
+    ///
+    /// Only one course is taken from each prerequisite group: a course
+    /// already in the order if there is one, and otherwise the lowest
+    /// course ID in the group which is in the catalog (or the lowest ID
+    /// overall, if none are).  Courses missing from the catalog are taken
+    /// as if they have no prerequisites.
+    ///
+    /// If the chosen courses require each other in a cycle, the members of
+    /// that cycle are returned as the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID};
+    /// #
+    /// let calc = CourseID::new("MATH", 1010);
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let alt_intro = CourseID::new("CSCI", 1010);
+    /// let data = CourseID::new("CSCI", 1200);
+    /// let algo = CourseID::new("CSCI", 2300);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&calc).build());
+    /// catalog.add_course(CourseBuilder::new(&alt_intro).build());
+    /// catalog.add_course(CourseBuilder::new(&intro).build());
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&data)
+    ///         .prereq_group(vec![intro.clone(), alt_intro.clone()])
+    ///         .build(),
+    /// );
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&algo)
+    ///         .prereq_group(vec![data.clone()])
+    ///         .prereq_group(vec![calc.clone()])
+    ///         .build(),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     catalog.suggested_order(&algo),
+    ///     Ok(vec![alt_intro, data.clone(), calc, algo])
+    /// );
+    ///
+    /// catalog.get_course_mut(&intro).unwrap().add_prereq(&data);
+    /// catalog.get_course_mut(&data).unwrap().add_prereq(&intro);
+    /// assert_eq!(catalog.suggested_order(&data), Err(vec![data, intro]));
+    /// ```
+    pub fn suggested_order(&self, coid: &CourseID) -> Result<Vec<CourseID>, Vec<CourseID>> {
+        let mut order = Vec::new();
+        self.take_in_order(coid, &mut Vec::new(), &mut order)?;
+        Ok(order)
+    }
+
+    /// Adds the course to `order` after the prerequisites chosen for it by
+    /// `suggested_order`.  `path` holds the courses currently being visited.
+    fn take_in_order(
+        &self,
+        coid: &CourseID,
+        path: &mut Vec<CourseID>,
+        order: &mut Vec<CourseID>,
+    ) -> Result<(), Vec<CourseID>> {
+        if order.contains(coid) {
+            return Ok(());
+        }
+
+        if let Some(pos) = path.iter().position(|visited| visited == coid) {
+            return Err(path[pos..].to_vec());
+        }
+
+        if let Some(course) = self.get_course(coid) {
+            path.push(coid.clone());
+            for group in &course.prereqs {
+                let mut options: Vec<&CourseID> =
+                    group.iter().filter(|prereq| *prereq != coid).collect();
+                options.sort();
+
+                if options.is_empty() || options.iter().any(|prereq| order.contains(prereq)) {
+                    continue;
+                }
+
+                let choice = options
+                    .iter()
+                    .find(|prereq| self.courses.contains_key(prereq))
+                    .unwrap_or(&options[0]);
+                self.take_in_order(choice, path, order)?;
+            }
+            path.pop();
+        }
+
+        order.push(coid.clone());
+        Ok(())
+    }
+
+    /// Returns a new catalog containing only the given course and the
+    /// courses it transitively requires.  Prerequisites and post options
+    /// referring to courses outside of that set are removed, dropping any
+    /// prerequisite group left empty.  Returns an empty catalog if the
+    /// course is not in this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID};
+    /// #
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let data = CourseID::new("CSCI", 1200);
+    /// let algo = CourseID::new("CSCI", 2300);
+    /// let art = CourseID::new("ARTS", 1010);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&intro).build());
+    /// catalog.add_course(CourseBuilder::new(&art).build());
+    /// catalog.add_course(CourseBuilder::new(&data).prereq_group(vec![intro.clone()]).build());
+    /// catalog.add_course(CourseBuilder::new(&algo).prereq_group(vec![data.clone()]).build());
+    ///
+    /// let subgraph = catalog.closure_subgraph(&data);
+    ///
+    /// assert!(subgraph.get_course(&data).is_some());
+    /// assert!(subgraph.get_course(&intro).is_some());
+    /// assert!(subgraph.get_course(&algo).is_none());
+    /// assert!(subgraph.get_course(&art).is_none());
+    /// assert_eq!(subgraph.prereq_edges(), vec![(intro, data)]);
+    /// ```
+    pub fn closure_subgraph(&self, coid: &CourseID) -> Catalog {
+        let tree = match self.get_course_tree(coid) {
+            Ok((tree, _)) => tree,
+            Err(_) => return Catalog::new(),
+        };
+        let members: HashSet<CourseID> = tree.iter().map(|course| course.get_id()).collect();
+
+        let mut subgraph = Catalog::new();
+        subgraph.has_substitutes = self.has_substitutes;
+        for course in tree {
+            let mut course = course.clone();
+
+            for group in course.prereqs.iter_mut() {
+                group.retain(|prereq| members.contains(prereq));
+            }
+            course.prereqs.retain(|group| !group.is_empty());
+            course.prereqs_opt.retain(|prereq| members.contains(prereq));
+            course
+                .prereq_grades
+                .retain(|prereq, _| members.contains(prereq));
+            course.post_options.retain(|post| members.contains(post));
+
+            subgraph.courses.insert(course.get_id(), course);
+        }
+
+        subgraph
+    }
+
+    /// Compares this catalog against a newer version of it, reporting the
+    /// courses which were added, removed, or changed.  Only complete
+    /// courses are compared, so placeholders created for undefined
+    /// prerequisites never show up as additions or removals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseChange, CourseID};
+    /// #
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let data = CourseID::new("CSCI", 1200);
+    /// let retired = CourseID::new("CSCI", 1300);
+    /// let calc = CourseID::new("MATH", 1010);
+    ///
+    /// let mut old = Catalog::new();
+    /// old.add_course(CourseBuilder::new(&intro).build());
+    /// old.add_course(CourseBuilder::new(&data).prereq_group(vec![intro.clone()]).build());
+    /// old.add_course(CourseBuilder::new(&retired).build());
+    ///
+    /// let mut new = Catalog::new();
+    /// new.add_course(CourseBuilder::new(&intro).build());
+    /// new.add_course(CourseBuilder::new(&calc).build());
+    /// new.add_course(
+    ///     CourseBuilder::new(&data)
+    ///         .prereq_group(vec![intro.clone()])
+    ///         .prereq_group(vec![calc.clone()])
+    ///         .build(),
+    /// );
+    ///
+    /// let diff = old.diff(&new);
+    ///
+    /// assert_eq!(diff.added, vec![calc.clone()]);
+    /// assert_eq!(diff.removed, vec![retired]);
+    /// assert_eq!(
+    ///     diff.changed[&data],
+    ///     vec![CourseChange::Prereqs {
+    ///         old: vec![vec![intro.clone()]],
+    ///         new: vec![vec![intro], vec![calc]],
+    ///     }]
+    /// );
+    /// assert_eq!(diff.changed.len(), 1);
+    /// assert!(old.diff(&old).is_empty());
+    /// ```
+    pub fn diff(&self, other: &Catalog) -> CatalogDiff {
+        fn sorted_groups(groups: &[HashSet<CourseID>]) -> Vec<Vec<CourseID>> {
+            let mut groups: Vec<Vec<CourseID>> = groups
+                .iter()
+                .map(|group| {
+                    let mut group: Vec<CourseID> = group.iter().cloned().collect();
+                    group.sort();
+                    group
+                })
+                .collect();
+            groups.sort();
+            groups
+        }
+
+        let complete = |catalog: &Catalog, coid: &CourseID| {
+            catalog
+                .get_course(coid)
+                .is_some_and(|course| course.complete)
+        };
+
+        let mut diff = CatalogDiff::default();
+
+        for (coid, course) in &self.courses {
+            if !course.complete {
+                continue;
+            }
+
+            let new_course = match other.get_course(coid) {
+                Some(new_course) if new_course.complete => new_course,
+                _ => {
+                    diff.removed.push(coid.clone());
+                    continue;
+                }
+            };
+
+            let mut changes = Vec::new();
+            if course.name != new_course.name {
+                changes.push(CourseChange::Name {
+                    old: course.name.clone(),
+                    new: new_course.name.clone(),
+                });
+            }
+            if course.credits != new_course.credits {
+                changes.push(CourseChange::Credits {
+                    old: course.credits,
+                    new: new_course.credits,
+                });
+            }
+
+            let old_prereqs = sorted_groups(&course.prereqs);
+            let new_prereqs = sorted_groups(&new_course.prereqs);
+            if old_prereqs != new_prereqs {
+                changes.push(CourseChange::Prereqs {
+                    old: old_prereqs,
+                    new: new_prereqs,
+                });
+            }
+
+            if !changes.is_empty() {
+                diff.changed.insert(coid.clone(), changes);
+            }
+        }
+
+        diff.added = other
+            .courses
+            .keys()
+            .filter(|coid| complete(other, coid) && !complete(self, coid))
+            .cloned()
+            .collect();
+
+        diff.added.sort();
+        diff.removed.sort();
+        diff
+    }
+}
+
+/// The differences between two versions of a catalog, as returned by
+/// `Catalog::diff`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CatalogDiff {
+    /// Courses only in the newer catalog, sorted by ID.
+    pub added: Vec<CourseID>,
+    /// Courses only in the older catalog, sorted by ID.
+    pub removed: Vec<CourseID>,
+    /// The changes to each course in both catalogs.
+    pub changed: BTreeMap<CourseID, Vec<CourseChange>>,
+}
+
+impl CatalogDiff {
+    /// Returns if the two catalogs are the same.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// A single change to a course between two versions of a catalog.
+/// Prerequisite groups are listed in sorted order, with each group's
+/// options sorted as well.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CourseChange {
+    Name {
+        old: String,
+        new: String,
+    },
+    Credits {
+        old: CreditRange,
+        new: CreditRange,
+    },
+    Prereqs {
+        old: Vec<Vec<CourseID>>,
+        new: Vec<Vec<CourseID>>,
+    },
+}
+
+/// A course's prerequisites, as returned by `Catalog::prereq_logic`.  Every
+/// group must be satisfied.
+pub type PrereqLogic = Vec<PrereqGroup>;
+
+/// One prerequisite group of a course, satisfied by taking any one of its
+/// alternatives.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PrereqGroup {
+    alternatives: Vec<CourseID>,
+}
+
+impl PrereqGroup {
+    /// Returns the courses which satisfy the group, sorted.
+    pub fn alternatives(&self) -> &[CourseID] {
+        &self.alternatives
+    }
+
+    /// Returns if the group has only one alternative, so that exact course
+    /// is required.
+    pub fn is_single(&self) -> bool {
+        self.alternatives.len() == 1
+    }
+}
+
+/// A course along with its relationships to the rest of the catalog, as
+/// returned by `Catalog::describe_course`.
+pub struct CourseDescription<'a> {
+    /// The described course.
+    pub course: &'a Course,
+    /// The course's prerequisite groups, only one course of each being
+    /// required.  Prerequisites missing from the catalog are left out.
+    pub prereq_groups: Vec<Vec<&'a Course>>,
+    /// Every course which lists the described course as a prerequisite,
+    /// sorted by ID.
+    pub dependents: Vec<&'a Course>,
+    /// The number of courses the described course transitively requires.
+    pub transitive_prereq_count: usize,
+}
+
+/// The contents of a catalog file.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+struct Courses {
+    courses: Vec<Course>,
+}
+
+/// An error encountered while loading a catalog from disk.
+#[derive(Debug)]
+pub enum CatalogError {
+    /// A file or directory could not be read.
+    Io { path: PathBuf, source: io::Error },
+    /// A file could not be parsed as a catalog.
+    Parse {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    /// A reader could not be read.
+    Read(io::Error),
+    /// A reader could not be parsed as a catalog.
+    Json(serde_json::Error),
+    /// A line of a CSV catalog is malformed.  Lines are numbered from 1.
+    Csv { line: usize, message: String },
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CatalogError::Io { path, source } => {
+                write!(f, "unable to read {}: {}", path.display(), source)
+            }
+            CatalogError::Parse { path, source } => {
+                write!(f, "unable to parse {}: {}", path.display(), source)
+            }
+            CatalogError::Read(source) => write!(f, "unable to read catalog: {}", source),
+            CatalogError::Json(source) => write!(f, "unable to parse catalog: {}", source),
+            CatalogError::Csv { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl Error for CatalogError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CatalogError::Io { source, .. } => Some(source),
+            CatalogError::Parse { source, .. } => Some(source),
+            CatalogError::Read(source) => Some(source),
+            CatalogError::Json(source) => Some(source),
+            CatalogError::Csv { .. } => None,
+        }
+    }
+}
+
+/// Splits one line of CSV into its fields, handling double-quoted fields.
+/// Returns `None` if a quoted field is never closed.
+fn split_csv_line(line: &str) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return None;
+    }
+
+    fields.push(field);
+    Some(fields)
+}
+
+/// Parses a catalog from a path, which is either a single catalog file or a
+/// directory of catalog files as read by `parse_catalog_dir`.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::{parse_catalog, CatalogError, Error};
+/// # use std::fs;
+/// #
+/// let missing = std::env::temp_dir().join("myca_parse_catalog_missing.json");
+/// match parse_catalog(&missing) {
+///     Err(Error::Catalog(CatalogError::Io { path, .. })) => assert_eq!(path, missing),
+///     _ => panic!("expected an Io error"),
+/// }
+///
+/// let malformed = std::env::temp_dir().join("myca_parse_catalog_malformed.json");
+/// fs::write(&malformed, r#"{"courses": 7}"#).unwrap();
+/// let result = parse_catalog(&malformed);
+/// fs::remove_file(&malformed).unwrap();
+///
+/// match result {
+///     Err(Error::Catalog(CatalogError::Parse { .. })) => (),
+///     _ => panic!("expected a Parse error"),
+/// }
+/// ```
+pub fn parse_catalog(path: &Path) -> Result<Catalog, crate::Error> {
+    if path.is_dir() {
+        Ok(parse_catalog_dir(path)?)
+    } else {
+        Ok(parse_catalog_file(path)?)
+    }
+}
+
+/// Parses a single catalog file of the form `{"courses": [...]}`.
+pub fn parse_catalog_file(path: &Path) -> Result<Catalog, CatalogError> {
+    let file = File::open(path).map_err(|source| CatalogError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    let courses: Courses =
+        serde_json::from_reader(BufReader::new(file)).map_err(|source| CatalogError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+    let mut catalog = Catalog::new();
+    for course in courses.courses {
+        catalog.add_course(course);
+    }
+
+    Ok(catalog)
+}
+
+/// Parses a catalog of the form `{"courses": [...]}` from a reader, adding
+/// each course to the catalog as soon as it is read.  Unlike
+/// `parse_catalog_file`, the full list of courses is never held in memory
+/// at once, which bounds peak memory for very large catalogs.
+pub fn parse_catalog_streaming<R: Read>(reader: R) -> Result<Catalog, CatalogError> {
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let catalog = deserializer
+        .deserialize_map(CatalogVisitor)
+        .map_err(CatalogError::Json)?;
+    deserializer.end().map_err(CatalogError::Json)?;
+
+    Ok(catalog)
+}
+
+/// Builds a catalog from the top level object of a catalog file.
+struct CatalogVisitor;
+
+impl<'de> Visitor<'de> for CatalogVisitor {
+    type Value = Catalog;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an object with a list of courses")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Catalog, A::Error> {
+        let mut catalog = Catalog::new();
+        let mut found = false;
+
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "courses" {
+                map.next_value_seed(CourseAdder(&mut catalog))?;
+                found = true;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+
+        if !found {
+            return Err(de::Error::missing_field("courses"));
+        }
+
+        Ok(catalog)
+    }
+}
+
+/// Adds each course of a list to the catalog as it is deserialized.
+struct CourseAdder<'a>(&'a mut Catalog);
+
+impl<'de, 'a> DeserializeSeed<'de> for CourseAdder<'a> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for CourseAdder<'a> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a list of courses")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while let Some(course) = seq.next_element::<Course>()? {
+            self.0.add_course(course);
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses every `*.json` file in a directory as a catalog file and merges
+/// them into a single catalog.  Files are merged in name order.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::{parse_catalog_dir, CourseID};
+/// # use std::fs;
+/// #
+/// let dir = std::env::temp_dir().join("myca_parse_catalog_dir_example");
+/// fs::create_dir_all(&dir).unwrap();
+///
+/// let course_json = |subj: &str| format!(r#"{{"courses": [{{
+/// #   "complete": true, "name": "", "description": "", "offered": "", "age_reqs": "",
+/// #   "prereqs": [], "prereqs_opt": [], "coreqs": [], "coreqs_opt": [], "post_options": [],
+///     "coid": {{"subj": "{}", "code": 1010}}
+/// }}]}}"#, subj);
+///
+/// fs::write(dir.join("csci.json"), course_json("CSCI")).unwrap();
+/// fs::write(dir.join("math.json"), course_json("MATH")).unwrap();
+///
+/// let catalog = parse_catalog_dir(&dir).unwrap();
+/// fs::remove_dir_all(&dir).unwrap();
+///
+/// assert!(catalog.get_course(&CourseID::new("CSCI", 1010)).is_some());
+/// assert!(catalog.get_course(&CourseID::new("MATH", 1010)).is_some());
+/// ```
+pub fn parse_catalog_dir(dir: &Path) -> Result<Catalog, CatalogError> {
+    let io_error = |source| CatalogError::Io {
+        path: dir.to_path_buf(),
+        source,
+    };
+
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut catalog = Catalog::new();
+    for path in paths {
+        catalog.merge(parse_catalog_file(&path)?);
+    }
+
+    Ok(catalog)
+}
+
+/// Returns the JSON Schema describing a catalog file, which is an object
+/// of the form `{"courses": [...]}`.
+///
+/// Requires the `schemars` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::catalog_json_schema;
+/// #
+/// let schema = catalog_json_schema();
+///
+/// assert!(schema.contains("\"coid\""));
+/// assert!(schema.contains("\"prereqs\""));
+/// assert!(schema.contains("\"coreqs\""));
+/// ```
+#[cfg(feature = "schemars")]
+pub fn catalog_json_schema() -> String {
+    let schema = schemars::schema_for!(Courses);
+    serde_json::to_string_pretty(&schema).unwrap()
+}
+
+/// A consistency problem found by `Catalog::validate`.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub enum CatalogWarning {
+    /// The course is part of a prerequisite cycle.
+    PrereqCycle(CourseID),
+    /// The course lists itself as a prerequisite or corequisite.
+    SelfPrereq(CourseID),
+    /// The course requires a course which is missing from the catalog or
+    /// is only a generated placeholder.
+    UndefinedPrereq { course: CourseID, prereq: CourseID },
+    /// The course is marked complete but has no name.
+    MissingName(CourseID),
+}
+
+impl CatalogWarning {
+    /// Returns if the warning makes affected courses impossible to schedule,
+    /// rather than only indicating incomplete data.
+    pub fn is_error(&self) -> bool {
+        match self {
+            CatalogWarning::PrereqCycle(_) | CatalogWarning::SelfPrereq(_) => true,
+            CatalogWarning::UndefinedPrereq { .. } | CatalogWarning::MissingName(_) => false,
+        }
+    }
+
+    /// Returns the course which caused the warning.
+    pub fn course(&self) -> &CourseID {
+        match self {
+            CatalogWarning::PrereqCycle(coid)
+            | CatalogWarning::SelfPrereq(coid)
+            | CatalogWarning::MissingName(coid) => coid,
+            CatalogWarning::UndefinedPrereq { course, .. } => course,
+        }
+    }
+}
+
+impl fmt::Display for CatalogWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CatalogWarning::PrereqCycle(coid) => {
+                write!(f, "{} is part of a prerequisite cycle", coid)
+            }
+            CatalogWarning::SelfPrereq(coid) => write!(f, "{} requires itself", coid),
+            CatalogWarning::UndefinedPrereq { course, prereq } => {
+                write!(f, "{} requires undefined course {}", course, prereq)
+            }
+            CatalogWarning::MissingName(coid) => {
+                write!(f, "{} is marked complete but has no name", coid)
+            }
+        }
+    }
+}
+
+pub(crate) mod course {
+    use std::cmp::Ordering;
+    use std::collections::{HashMap, HashSet};
+    use std::convert::TryFrom;
+    use std::error::Error;
+    use std::fmt;
+    use std::str::FromStr;
+    use std::sync::{OnceLock, RwLock};
+
+    use serde::{Deserialize, Serialize};
+
+    use super::Catalog;
+    use crate::schedule::{parse_offered, Season};
+
+    /// Used to identify courses in the schedule and catalog.  CourseIDs are
+    /// ordered by subject, then by code.
+    ///
+    /// Subjects are interned into a table shared by every CourseID, so a
+    /// CourseID is only a pair of small integers and cloning one never
+    /// allocates.  The table holds at most 65,536 distinct subjects, and
+    /// its entries are kept for the life of the program.
+    ///
+    /// CourseIDs are serialized as `{"subj": ..., "code": ...}` objects, but
+    /// a string such as `"CSCI 1100"` is also accepted when deserializing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::CourseID;
+    /// # use serde_json::json;
+    /// #
+    /// let object: CourseID = serde_json::from_value(json!({"subj": "CSCI", "code": 1100})).unwrap();
+    /// let string: CourseID = serde_json::from_value(json!("CSCI 1100")).unwrap();
+    ///
+    /// assert_eq!(object, string);
+    /// assert!(serde_json::from_value::<CourseID>(json!("csci")).is_err());
+    /// ```
+    #[derive(Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+    #[serde(try_from = "CourseIDRepr", into = "CourseIDRepr")]
+    pub struct CourseID {
+        subj: SubjectId,
+        code: u16,
+    }
+
+    #[derive(Deserialize, Serialize)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(untagged)]
+    enum CourseIDRepr {
+        Text(String),
+        Parts { subj: String, code: u16 },
+    }
+
+    impl TryFrom<CourseIDRepr> for CourseID {
+        type Error = ParseCourseIDError;
+
+        fn try_from(repr: CourseIDRepr) -> Result<Self, Self::Error> {
+            match repr {
+                CourseIDRepr::Text(text) => text.parse(),
+                CourseIDRepr::Parts { subj, code } => CourseID::try_new(&subj, code)
+                    .ok_or_else(|| ParseCourseIDError::too_many_subjects(&subj)),
+            }
+        }
+    }
+
+    impl From<CourseID> for CourseIDRepr {
+        fn from(coid: CourseID) -> Self {
+            CourseIDRepr::Parts {
+                subj: String::from(coid.subj()),
+                code: coid.code,
+            }
+        }
+    }
+
+    /// CourseIDs are described by the forms they are read from rather than
+    /// their interned representation.
+    #[cfg(feature = "schemars")]
+    impl schemars::JsonSchema for CourseID {
+        fn schema_name() -> String {
+            String::from("CourseID")
+        }
+
+        fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+            CourseIDRepr::json_schema(gen)
+        }
+    }
+
+    /// An index into the table of interned subjects.
+    #[derive(PartialEq, Eq, Hash, Clone, Copy)]
+    struct SubjectId(u16);
+
+    /// Every subject a CourseID has been created with.  Subjects are never
+    /// removed, so their strings are leaked to hand out `'static` references;
+    /// the number of subjects is bounded by the width of `SubjectId`.
+    #[derive(Default)]
+    struct SubjectTable {
+        names: Vec<&'static str>,
+        ids: HashMap<&'static str, SubjectId>,
+    }
+
+    fn subject_table() -> &'static RwLock<SubjectTable> {
+        static SUBJECTS: OnceLock<RwLock<SubjectTable>> = OnceLock::new();
+        SUBJECTS.get_or_init(Default::default)
+    }
+
+    impl SubjectId {
+        /// Returns the ID of the subject, adding it to the table if it is
+        /// new, or `None` if the table is already full.
+        fn intern(subj: &str) -> Option<Self> {
+            if let Some(&id) = subject_table().read().unwrap().ids.get(subj) {
+                return Some(id);
+            }
+
+            let mut table = subject_table().write().unwrap();
+            if let Some(&id) = table.ids.get(subj) {
+                return Some(id);
+            }
+
+            let id = SubjectId(u16::try_from(table.names.len()).ok()?);
+            let name: &'static str = Box::leak(subj.to_owned().into_boxed_str());
+            table.names.push(name);
+            table.ids.insert(name, id);
+            Some(id)
+        }
+
+        fn name(self) -> &'static str {
+            subject_table().read().unwrap().names[usize::from(self.0)]
+        }
+    }
+
+    impl Ord for CourseID {
+        fn cmp(&self, other: &Self) -> Ordering {
+            if self.subj == other.subj {
+                return self.code.cmp(&other.code);
+            }
+
+            self.subj()
+                .cmp(other.subj())
+                .then(self.code.cmp(&other.code))
+        }
+    }
+
+    impl PartialOrd for CourseID {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl fmt::Debug for CourseID {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.debug_struct("CourseID")
+                .field("subj", &self.subj())
+                .field("code", &self.code)
+                .finish()
+        }
+    }
+
+    impl CourseID {
+        /// Constructs a new course from a given subject and course code.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `subj` is a new subject and 65,536 distinct subjects
+        /// have already been used.  Parsing and deserializing return an
+        /// error in that case instead.
+        pub fn new(subj: &str, code: u16) -> Self {
+            CourseID::try_new(subj, code).expect("too many distinct course subjects")
+        }
+
+        /// Constructs a new course, or returns `None` if the subject table
+        /// is full.
+        fn try_new(subj: &str, code: u16) -> Option<Self> {
+            Some(CourseID {
+                subj: SubjectId::intern(subj)?,
+                code,
+            })
+        }
+
+        /// Returns the subject of the course, e.g. "CSCI".
+        pub fn subj(&self) -> &str {
+            self.subj.name()
+        }
+
+        /// Returns the numeric code of the course.
+        pub fn code(&self) -> u16 {
+            self.code
+        }
+
+        /// Parses a course ID from a string.  The string must be made up of
+        /// an uppercase subject followed by a numeric code, optionally
+        /// separated by a single space or hyphen.  The following forms are
+        /// all accepted:
+        ///
+        /// - "CSCI 1100"
+        /// - "CSCI-1100"
+        /// - "CSCI1100"
+        ///
+        /// Returns the parsed CourseID if input string is valid, or `None` if
+        /// it is not or its subject would exceed the limit on distinct
+        /// subjects.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use myca::CourseID;
+        /// #
+        /// let coid = CourseID::from("TEST 1100").unwrap();
+        /// assert_eq!(coid, CourseID::new("TEST", 1100));
+        ///
+        /// assert_eq!(CourseID::from("CS101"), Some(CourseID::new("CS", 101)));
+        /// assert_eq!(CourseID::from("CS-101"), Some(CourseID::new("CS", 101)));
+        /// assert_eq!(CourseID::from("CS 101"), Some(CourseID::new("CS", 101)));
+        ///
+        /// assert!(CourseID::from("CS  101").is_none());
+        /// assert!(CourseID::from("cs 101").is_none());
+        /// assert!(CourseID::from("101").is_none());
+        /// ```
+        pub fn from(coid: &str) -> Option<CourseID> {
+            let (subj, code) = CourseID::split(coid)?;
+            CourseID::try_new(subj, code)
+        }
+
+        /// Splits a string in the CourseID format into its subject and code.
+        fn split(coid: &str) -> Option<(&str, u16)> {
+            let subj_len = coid
+                .find(|c: char| !c.is_ascii_uppercase())
+                .unwrap_or(coid.len());
+
+            if subj_len == 0 {
+                return None;
+            }
+
+            let (subj, rest) = coid.split_at(subj_len);
+
+            let code_str = match rest.chars().next() {
+                Some(' ') | Some('-') => &rest[1..],
+                _ => rest,
+            };
+
+            if code_str.is_empty() || !code_str.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+
+            let code = match code_str.parse::<u16>() {
+                Ok(num) => num,
+                _ => return None,
+            };
+
+            Some((subj, code))
+        }
+
+        /// Parses a comma-separated list of course IDs, such as
+        /// "CSCI 1100, MATH 1010".  Each token is trimmed and parsed with
+        /// `CourseID::from`; empty tokens are skipped.  Returns the parsed
+        /// IDs along with the tokens which failed to parse.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use myca::CourseID;
+        /// #
+        /// let (coids, invalid) = CourseID::parse_many("CS 101, MATH-101,, physics");
+        ///
+        /// assert_eq!(coids, vec![CourseID::new("CS", 101), CourseID::new("MATH", 101)]);
+        /// assert_eq!(invalid, vec![String::from("physics")]);
+        /// ```
+        pub fn parse_many(s: &str) -> (Vec<CourseID>, Vec<String>) {
+            let mut coids = Vec::new();
+            let mut invalid = Vec::new();
+
+            for token in s
+                .split(',')
+                .map(str::trim)
+                .filter(|token| !token.is_empty())
+            {
+                match CourseID::from(token) {
+                    Some(coid) => coids.push(coid),
+                    None => invalid.push(String::from(token)),
+                }
+            }
+
+            (coids, invalid)
+        }
+    }
+
+    /// CourseIDs are printed in the format: "SUBJ CODE"
+    ///
+    /// For every CourseID with a non-empty, uppercase ASCII subject, the
+    /// printed form parses back into the same CourseID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::CourseID;
+    /// #
+    /// let coid = CourseID::new("TEST", 1100);
+    /// let coid_str = format!("{}", coid);
+    ///
+    /// assert_eq!(coid_str, "TEST 1100");
+    ///
+    /// for subj in &["A", "CS", "MATH", "ABCDEFGH"] {
+    ///     for &code in &[0, 7, 42, 101, 1100, 9999, 10000, u16::MAX] {
+    ///         let coid = CourseID::new(subj, code);
+    ///         assert_eq!(CourseID::from(&coid.to_string()), Some(coid.clone()));
+    ///         assert_eq!(coid.to_string().parse::<CourseID>(), Ok(coid));
+    ///     }
+    /// }
+    /// ```
+    impl fmt::Display for CourseID {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{} {}", self.subj(), self.code)
+        }
+    }
+
+    /// The error returned when a string is not in the CourseID format, or
+    /// when its subject would exceed the limit on distinct subjects.
+    #[derive(PartialEq, Eq, Clone, Debug)]
+    pub struct ParseCourseIDError {
+        input: String,
+        too_many_subjects: bool,
+    }
+
+    impl ParseCourseIDError {
+        fn invalid_format(input: &str) -> Self {
+            ParseCourseIDError {
+                input: String::from(input),
+                too_many_subjects: false,
+            }
+        }
+
+        fn too_many_subjects(input: &str) -> Self {
+            ParseCourseIDError {
+                input: String::from(input),
+                too_many_subjects: true,
+            }
+        }
+    }
+
+    impl fmt::Display for ParseCourseIDError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            if self.too_many_subjects {
+                write!(
+                    f,
+                    "'{}' would exceed the limit of 65,536 distinct course subjects",
+                    self.input
+                )
+            } else {
+                write!(f, "'{}' is not in the Course ID format", self.input)
+            }
+        }
+    }
+
+    impl Error for ParseCourseIDError {}
+
+    /// CourseIDs are parsed with the same rules as `CourseID::from`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::CourseID;
+    /// #
+    /// let coid: CourseID = "TEST-1100".parse().unwrap();
+    /// assert_eq!(coid, CourseID::new("TEST", 1100));
+    ///
+    /// assert!("1100".parse::<CourseID>().is_err());
+    /// ```
+    impl FromStr for CourseID {
+        type Err = ParseCourseIDError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (subj, code) =
+                CourseID::split(s).ok_or_else(|| ParseCourseIDError::invalid_format(s))?;
+            CourseID::try_new(subj, code).ok_or_else(|| ParseCourseIDError::too_many_subjects(s))
+        }
+    }
+
+    /// The number of credits a course is worth.  Fixed-credit courses have
+    /// equal minimum and maximum values.
+    ///
+    /// Credits may be given in the catalog as a single number
+    /// (`"credits": 4`), as an object (`{"min": 1, "max": 4}`), or as a
+    /// two-element array (`[1, 4]`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::CreditRange;
+    /// # use serde_json::json;
+    /// #
+    /// let fixed: CreditRange = serde_json::from_value(json!(4)).unwrap();
+    /// assert_eq!(fixed, CreditRange::fixed(4));
+    ///
+    /// let variable: CreditRange = serde_json::from_value(json!({"min": 1, "max": 4})).unwrap();
+    /// assert_eq!(variable, CreditRange::new(1, 4));
+    ///
+    /// let pair: CreditRange = serde_json::from_value(json!([1, 4])).unwrap();
+    /// assert_eq!(pair, variable);
+    /// ```
+    #[derive(Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(from = "CreditRepr")]
+    pub struct CreditRange {
+        min: u8,
+        max: u8,
+    }
+
+    #[derive(Deserialize)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(untagged)]
+    enum CreditRepr {
+        Fixed(u8),
+        Range { min: u8, max: u8 },
+        Pair([u8; 2]),
+    }
+
+    impl From<CreditRepr> for CreditRange {
+        fn from(repr: CreditRepr) -> Self {
+            match repr {
+                CreditRepr::Fixed(credits) => CreditRange::fixed(credits),
+                CreditRepr::Range { min, max } => CreditRange::new(min, max),
+                CreditRepr::Pair([min, max]) => CreditRange::new(min, max),
+            }
+        }
+    }
+
+    impl CreditRange {
+        /// Constructs a credit range.  If `min` is greater than `max` the
+        /// two are swapped.
+        pub fn new(min: u8, max: u8) -> Self {
+            if min > max {
+                CreditRange { min: max, max: min }
+            } else {
+                CreditRange { min, max }
+            }
+        }
+
+        /// Constructs a credit range for a fixed-credit course.
+        pub fn fixed(credits: u8) -> Self {
+            CreditRange {
+                min: credits,
+                max: credits,
+            }
+        }
+
+        /// Returns the minimum number of credits.
+        pub fn min(&self) -> u8 {
+            self.min
+        }
+
+        /// Returns the maximum number of credits.
+        pub fn max(&self) -> u8 {
+            self.max
+        }
+
+        /// Returns if the course is worth a fixed number of credits.
+        pub fn is_fixed(&self) -> bool {
+            self.min == self.max
+        }
+    }
+
+    /// A letter grade.  Grades are ordered from `F` (lowest) to `A`
+    /// (highest).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::Grade;
     /// #
-    /// let mut catalog = Catalog::new();
-    /// let coid = CourseID::new("TEST", 1200);
-    ///
-    /// catalog.emplace_course(&coid);
-    ///
-    /// let course = catalog.get_course_mut(&coid).unwrap();
-    ///
-    /// assert_eq!(course.get_id(), coid);
-    ///
-    /// course.add_prereq(&CourseID::new("TEST", 1100));
+    /// assert!(Grade::D < Grade::C);
+    /// assert_eq!(format!("{}", Grade::B), "B");
+    /// assert_eq!("c".parse::<Grade>(), Ok(Grade::C));
     /// ```
-    pub fn get_course_mut(&mut self, coid: &CourseID) -> Option<&mut Course> {
-        self.courses.get_mut(coid)
+    #[derive(Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    pub enum Grade {
+        F,
+        D,
+        C,
+        B,
+        A,
     }
-}
-
-pub(crate) mod course {
-    use std::collections::HashSet;
-    use std::fmt;
-
-    use serde::{Deserialize, Serialize};
 
-    /// Used to identify courses in the schedule and catalog.
-    #[derive(Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Debug)]
-    pub struct CourseID {
-        subj: String,
-        code: u16,
+    impl fmt::Display for Grade {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{:?}", self)
+        }
     }
 
-    impl CourseID {
-        /// Constructs a new course from a given subject and course code.
-        pub fn new(subj: &str, code: u16) -> Self {
-            CourseID {
-                subj: String::from(subj),
-                code,
+    impl FromStr for Grade {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.trim().to_ascii_uppercase().as_str() {
+                "A" => Ok(Grade::A),
+                "B" => Ok(Grade::B),
+                "C" => Ok(Grade::C),
+                "D" => Ok(Grade::D),
+                "F" => Ok(Grade::F),
+                _ => Err(format!("'{}' is not a letter grade", s)),
             }
         }
+    }
 
-        /// Parses a course ID from a string.  The string must be 8 or 9
-        /// characters long.  The first four characters are parsed as the
-        /// course's subject and the last four characters are parsed
-        /// as the course's code.
-        /// Returns the parsed CourseID if input string is valid.
-        ///
-        /// # Examples
-        ///
-        /// ```
-        /// # use myca::CourseID;
-        /// #
-        /// let coid = CourseID::from("TEST 1100").unwrap();
-        /// assert_eq!(coid, CourseID::new("TEST", 1100));
-        /// ```
-        pub fn from(coid: &str) -> Option<CourseID> {
-            let char_vec: Vec<char> = coid.chars().collect();
+    /// A single prerequisite as written in the catalog: either a bare
+    /// course, or a course with the minimum grade it must be passed with.
+    #[derive(Deserialize, Serialize)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(untagged)]
+    enum PrereqEntry {
+        Graded { course: CourseID, min_grade: Grade },
+        Bare(CourseID),
+    }
 
-            if char_vec.len() != 8 && char_vec.len() != 9 {
-                return None;
-            }
+    /// The catalog representation of a course.  Prerequisites carry their
+    /// grade requirements inline, which `Course` stores separately.
+    #[derive(Deserialize, Serialize)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    struct CourseRepr {
+        #[serde(default = "default_complete")]
+        complete: bool,
 
-            let code_str: String = char_vec[char_vec.len() - 4..].iter().collect();
+        name: String,
+        #[serde(default)]
+        description: String,
 
-            let code = match code_str.parse::<u16>() {
-                Ok(num) => num,
-                _ => return None,
-            };
+        #[serde(alias = "course_id")]
+        coid: CourseID,
 
-            for character in &char_vec[..4] {
-                if character < &'A' || character > &'Z' {
-                    return None;
-                }
+        #[serde(default)]
+        credits: CreditRange,
+
+        #[serde(default)]
+        offered: String,
+        #[serde(default)]
+        age_reqs: String,
+
+        #[serde(default)]
+        repeatable: bool,
+        #[serde(default)]
+        tags: HashSet<String>,
+        #[serde(default)]
+        antireqs: HashSet<CourseID>,
+        #[serde(default)]
+        substitutes_for: Option<CourseID>,
+
+        #[serde(default, alias = "prerequisites")]
+        prereqs: Vec<Vec<PrereqEntry>>,
+        #[serde(default)]
+        prereqs_opt: HashSet<CourseID>,
+
+        #[serde(default, alias = "corequisites")]
+        coreqs: Vec<HashSet<CourseID>>,
+        #[serde(default)]
+        coreqs_opt: HashSet<CourseID>,
+
+        #[serde(default)]
+        post_options: HashSet<CourseID>,
+    }
+
+    /// Courses written out by hand are assumed to be complete.
+    fn default_complete() -> bool {
+        true
+    }
+
+    impl From<CourseRepr> for Course {
+        fn from(repr: CourseRepr) -> Self {
+            let mut prereq_grades = HashMap::new();
+            let prereqs = repr
+                .prereqs
+                .into_iter()
+                .map(|group| {
+                    group
+                        .into_iter()
+                        .map(|entry| match entry {
+                            PrereqEntry::Graded { course, min_grade } => {
+                                prereq_grades.insert(course.clone(), min_grade);
+                                course
+                            }
+                            PrereqEntry::Bare(course) => course,
+                        })
+                        .collect()
+                })
+                .collect();
+
+            Course {
+                complete: repr.complete,
+                name: repr.name,
+                description: repr.description,
+                coid: repr.coid,
+                credits: repr.credits,
+                offered: repr.offered,
+                age_reqs: repr.age_reqs,
+                repeatable: repr.repeatable,
+                tags: repr.tags,
+                antireqs: repr.antireqs,
+                substitutes_for: repr.substitutes_for,
+                prereqs,
+                prereqs_opt: repr.prereqs_opt,
+                prereq_grades,
+                coreqs: repr.coreqs,
+                coreqs_opt: repr.coreqs_opt,
+                post_options: repr.post_options,
             }
+        }
+    }
 
-            let subj: String = char_vec[..4].iter().collect();
+    impl From<Course> for CourseRepr {
+        fn from(course: Course) -> Self {
+            let prereq_grades = course.prereq_grades;
+            let prereqs = course
+                .prereqs
+                .into_iter()
+                .map(|group| {
+                    group
+                        .into_iter()
+                        .map(|coid| match prereq_grades.get(&coid) {
+                            Some(&min_grade) => PrereqEntry::Graded {
+                                course: coid,
+                                min_grade,
+                            },
+                            None => PrereqEntry::Bare(coid),
+                        })
+                        .collect()
+                })
+                .collect();
 
-            Some(CourseID { subj, code })
+            CourseRepr {
+                complete: course.complete,
+                name: course.name,
+                description: course.description,
+                coid: course.coid,
+                credits: course.credits,
+                offered: course.offered,
+                age_reqs: course.age_reqs,
+                repeatable: course.repeatable,
+                tags: course.tags,
+                antireqs: course.antireqs,
+                substitutes_for: course.substitutes_for,
+                prereqs,
+                prereqs_opt: course.prereqs_opt,
+                coreqs: course.coreqs,
+                coreqs_opt: course.coreqs_opt,
+                post_options: course.post_options,
+            }
         }
     }
 
-    /// CourseIDs are printed in the format: "SUBJ CODE"
+    /// Stores all information related to a course.
+    ///
+    /// This should only be generated when parsed from a json file.
+    ///
+    /// Along with the crate's own key names, the long-form keys
+    /// `"course_id"`, `"prerequisites"`, and `"corequisites"` used by other
+    /// catalog exports are accepted when deserializing.
+    ///
+    /// A prerequisite may be given as `{"course": ..., "min_grade": "C"}` to
+    /// require that it is passed with at least that grade.  Courses which
+    /// may be taken more than once are marked `"repeatable": true`, and any
+    /// attributes are listed under `"tags"`.  Courses which may not both be
+    /// taken for credit are listed under `"antireqs"`, and an honors or
+    /// other variant of a course names it under `"substitutes_for"`.
+    ///
+    /// Only the ID and name are required.  Every other field defaults to
+    /// empty, and a course is assumed to be complete unless it is marked
+    /// `"complete": false`.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use myca::CourseID;
+    /// # use myca::{Course, CourseID};
+    /// # use serde_json::json;
     /// #
-    /// let coid = CourseID::new("TEST", 1100);
-    /// let coid_str = format!("{}", coid);
+    /// let course: Course = serde_json::from_value(json!({
+    ///     "coid": { "subj": "TEST", "code": 1100 },
+    ///     "name": "Introduction to Testing"
+    /// })).unwrap();
     ///
-    /// assert_eq!(coid_str, "TEST 1100");
-    /// ```
-    impl fmt::Display for CourseID {
-        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            write!(f, "{} {}", self.subj, self.code)
-        }
-    }
-
-    /// Stores all information related to a course.
+    /// assert_eq!(course.get_id(), CourseID::new("TEST", 1100));
+    /// assert_eq!(course.name(), "Introduction to Testing");
+    /// assert!(course.prereq_sets().is_empty());
+    /// assert_eq!(serde_json::to_value(&course).unwrap()["complete"], json!(true));
     ///
-    /// This should only be generated when parsed from a json file.
-    #[derive(Deserialize, Serialize)]
+    /// let course: Course = serde_json::from_value(json!({
+    ///     "name": "",
+    ///     "course_id": { "subj": "TEST", "code": 1200 },
+    ///     "prerequisites": [[{ "subj": "TEST", "code": 1100 }]],
+    ///     "corequisites": [[{ "subj": "TEST", "code": 1210 }]]
+    /// })).unwrap();
+    ///
+    /// assert_eq!(course.get_id(), CourseID::new("TEST", 1200));
+    /// assert!(course.prereq_sets()[0].contains(&CourseID::new("TEST", 1100)));
+    /// assert!(course.coreq_sets()[0].contains(&CourseID::new("TEST", 1210)));
+    /// ```
+    #[derive(Deserialize, Serialize, Clone)]
+    #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+    #[serde(from = "CourseRepr", into = "CourseRepr")]
     #[allow(dead_code)] // TODO: use all of the fields
     pub struct Course {
         pub(super) complete: bool,
@@ -231,11 +3054,19 @@ pub(crate) mod course {
 
         pub(super) coid: CourseID,
 
+        pub(super) credits: CreditRange,
+
         pub(super) offered: String,
         pub(super) age_reqs: String,
 
+        pub(super) repeatable: bool,
+        pub(super) tags: HashSet<String>,
+        pub(super) antireqs: HashSet<CourseID>,
+        pub(super) substitutes_for: Option<CourseID>,
+
         pub(super) prereqs: Vec<HashSet<CourseID>>,
         pub(super) prereqs_opt: HashSet<CourseID>,
+        pub(super) prereq_grades: HashMap<CourseID, Grade>,
 
         pub(super) coreqs: Vec<HashSet<CourseID>>,
         pub(super) coreqs_opt: HashSet<CourseID>,
@@ -250,16 +3081,37 @@ pub(crate) mod course {
                 name: String::new(),
                 description: String::new(),
                 coid: coid.clone(),
+                credits: CreditRange::default(),
                 offered: String::new(),
                 age_reqs: String::new(),
+                repeatable: false,
+                tags: HashSet::new(),
+                antireqs: HashSet::new(),
+                substitutes_for: None,
                 prereqs: Vec::new(),
                 prereqs_opt: HashSet::new(),
+                prereq_grades: HashMap::new(),
                 coreqs: Vec::new(),
                 coreqs_opt: HashSet::new(),
                 post_options: HashSet::new(),
             }
         }
 
+        /// Constructs a placeholder for a course which is only known as a
+        /// requisite of another course.  Placeholders are not marked
+        /// complete.
+        pub(super) fn placeholder(coid: &CourseID) -> Self {
+            Course {
+                complete: false,
+                ..Course::new(coid)
+            }
+        }
+
+        /// Returns a builder for a complete course with the given ID.
+        pub fn builder(coid: &CourseID) -> CourseBuilder {
+            CourseBuilder::new(coid)
+        }
+
         /// Returns the CourseID corresponding to this course.
         ///
         /// # Examples
@@ -292,6 +3144,167 @@ pub(crate) mod course {
             self.coid.clone()
         }
 
+        /// Returns the name of the course.  Placeholder courses have an
+        /// empty name.
+        pub fn name(&self) -> &str {
+            &self.name
+        }
+
+        /// Returns a single line describing the course for compact listings,
+        /// in the form "SUBJ CODE (N cr): Name".  Variable credits are shown
+        /// as a range, and courses without credits omit them.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use myca::{CourseBuilder, CourseID, CreditRange};
+        /// #
+        /// let course = CourseBuilder::new(&CourseID::new("CSCI", 1200))
+        ///     .name("Data Structures")
+        ///     .credits(CreditRange::fixed(4))
+        ///     .build();
+        /// assert_eq!(course.summary_line(), "CSCI 1200 (4 cr): Data Structures");
+        ///
+        /// let research = CourseBuilder::new(&CourseID::new("CSCI", 4990))
+        ///     .name("Research")
+        ///     .credits(CreditRange::new(1, 4))
+        ///     .build();
+        /// assert_eq!(research.summary_line(), "CSCI 4990 (1-4 cr): Research");
+        ///
+        /// let seminar = CourseBuilder::new(&CourseID::new("CSCI", 1000))
+        ///     .name("Seminar")
+        ///     .credits(CreditRange::fixed(0))
+        ///     .build();
+        /// assert_eq!(seminar.summary_line(), "CSCI 1000: Seminar");
+        /// ```
+        pub fn summary_line(&self) -> String {
+            let credits = &self.credits;
+            if credits.max() == 0 {
+                format!("{}: {}", self.coid, self.name)
+            } else if credits.is_fixed() {
+                format!("{} ({} cr): {}", self.coid, credits.min(), self.name)
+            } else {
+                format!(
+                    "{} ({}-{} cr): {}",
+                    self.coid,
+                    credits.min(),
+                    credits.max(),
+                    self.name
+                )
+            }
+        }
+
+        /// Returns if the course may be taken for credit more than once,
+        /// such as a seminar or independent study.
+        pub fn is_repeatable(&self) -> bool {
+            self.repeatable
+        }
+
+        /// Returns the attributes the course carries, such as "HASS" or
+        /// "Writing Intensive".
+        pub fn tags(&self) -> &HashSet<String> {
+            &self.tags
+        }
+
+        /// Returns the courses which may not be taken for credit alongside
+        /// this one, such as the honors version of the same course.
+        pub fn antireqs(&self) -> &HashSet<CourseID> {
+            &self.antireqs
+        }
+
+        /// Returns the course this one may be taken in place of, such as the
+        /// regular version of an honors course.  Unlike an equivalency this
+        /// only works in one direction: wherever the base course is required
+        /// this course is accepted, but not the other way around.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use myca::{Catalog, CourseBuilder, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+        /// #
+        /// let intro = CourseID::new("CSCI", 1010);
+        /// let honors = CourseID::new("CSCI", 1019);
+        /// let data = CourseID::new("CSCI", 2010);
+        ///
+        /// let mut catalog = Catalog::new();
+        /// catalog.add_course(CourseBuilder::new(&intro).build());
+        /// catalog.add_course(CourseBuilder::new(&honors).substitutes_for(&intro).build());
+        /// catalog.add_course(CourseBuilder::new(&data).prereq_group(vec![intro.clone()]).build());
+        ///
+        /// assert_eq!(catalog.get_course(&honors).unwrap().substitutes_for(), Some(&intro));
+        ///
+        /// let mut schedule = Schedule::new();
+        /// schedule.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![honors.clone()]));
+        /// schedule.add_semester(Semester::new(SemTime::Spring(2020)));
+        ///
+        /// let config = GenerationConfig::default();
+        /// assert_eq!(schedule.explain_placement(&data, &SemTime::Spring(2020), &catalog, &config), None);
+        ///
+        /// // The base course does not satisfy a requirement for the honors one
+        /// let honors_only = CourseID::new("CSCI", 2019);
+        /// catalog.add_course(CourseBuilder::new(&honors_only).prereq_group(vec![honors.clone()]).build());
+        ///
+        /// let mut regular = Schedule::new();
+        /// regular.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![intro.clone()]));
+        /// regular.add_semester(Semester::new(SemTime::Spring(2020)));
+        ///
+        /// assert!(regular.explain_placement(&honors_only, &SemTime::Spring(2020), &catalog, &config).is_some());
+        /// ```
+        pub fn substitutes_for(&self) -> Option<&CourseID> {
+            self.substitutes_for.as_ref()
+        }
+
+        /// Returns the number of credits the course is worth.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use myca::{Course, CreditRange};
+        /// # use serde_json::json;
+        /// #
+        /// let course: Course = serde_json::from_value(json!({
+        /// # "complete": true,
+        /// # "name": "",
+        /// # "description": "",
+        /// # "offered": "",
+        /// # "age_reqs": "",
+        /// # "prereqs": [],
+        /// # "prereqs_opt": [],
+        /// # "coreqs": [],
+        /// # "coreqs_opt": [],
+        /// # "post_options": [],
+        ///     "coid": {
+        ///         "subj": "TEST",
+        ///         "code": 4980
+        ///     },
+        ///     "credits": [1, 4]
+        /// })).unwrap();
+        ///
+        /// assert_eq!(course.credits(), CreditRange::new(1, 4));
+        /// ```
+        pub fn credits(&self) -> CreditRange {
+            self.credits
+        }
+
+        /// Returns the seasons the course is offered in, as parsed by
+        /// `parse_offered`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use myca::{CourseBuilder, CourseID, Season};
+        /// #
+        /// let course = CourseBuilder::new(&CourseID::new("TEST", 1100))
+        ///     .offered("Fall")
+        ///     .build();
+        ///
+        /// assert!(course.offered_seasons().contains(&Season::Fall));
+        /// assert!(!course.offered_seasons().contains(&Season::Spring));
+        /// ```
+        pub fn offered_seasons(&self) -> HashSet<Season> {
+            parse_offered(&self.offered)
+        }
+
         /// Adds a prerequisite to the course.
         ///
         /// # Examples
@@ -333,10 +3346,92 @@ pub(crate) mod course {
             self.prereqs.push(hashset);
         }
 
+        /// Changes the subject of the course and of every course it refers
+        /// to from `old` to `new`.  Returns if anything was changed.
+        pub(super) fn rename_subject(&mut self, old: &str, new: &str) -> bool {
+            fn rename(coid: &CourseID, old: &str, new: &str) -> CourseID {
+                if coid.subj() == old {
+                    CourseID::new(new, coid.code)
+                } else {
+                    coid.clone()
+                }
+            }
+
+            fn rename_set(set: &HashSet<CourseID>, old: &str, new: &str) -> HashSet<CourseID> {
+                set.iter().map(|coid| rename(coid, old, new)).collect()
+            }
+
+            let refers_to_old = std::iter::once(&self.coid)
+                .chain(self.prereqs.iter().flatten())
+                .chain(self.prereqs_opt.iter())
+                .chain(self.coreqs.iter().flatten())
+                .chain(self.coreqs_opt.iter())
+                .chain(self.post_options.iter())
+                .chain(self.antireqs.iter())
+                .chain(self.substitutes_for.iter())
+                .any(|coid| coid.subj() == old);
+
+            if !refers_to_old {
+                return false;
+            }
+
+            self.coid = rename(&self.coid, old, new);
+            self.prereqs = self
+                .prereqs
+                .iter()
+                .map(|set| rename_set(set, old, new))
+                .collect();
+            self.prereqs_opt = rename_set(&self.prereqs_opt, old, new);
+            self.prereq_grades = self
+                .prereq_grades
+                .iter()
+                .map(|(coid, &grade)| (rename(coid, old, new), grade))
+                .collect();
+            self.coreqs = self
+                .coreqs
+                .iter()
+                .map(|set| rename_set(set, old, new))
+                .collect();
+            self.coreqs_opt = rename_set(&self.coreqs_opt, old, new);
+            self.post_options = rename_set(&self.post_options, old, new);
+            self.antireqs = rename_set(&self.antireqs, old, new);
+            self.substitutes_for = self
+                .substitutes_for
+                .as_ref()
+                .map(|coid| rename(coid, old, new));
+
+            true
+        }
+
+        /// Removes the course's own ID from its requisites, dropping any
+        /// requirement group left empty.  Returns if anything was removed.
+        pub(super) fn remove_self_references(&mut self) -> bool {
+            let coid = self.coid.clone();
+            let mut removed = false;
+
+            for sets in [&mut self.prereqs, &mut self.coreqs].iter_mut() {
+                for set in sets.iter_mut() {
+                    removed |= set.remove(&coid);
+                }
+                sets.retain(|set| !set.is_empty());
+            }
+
+            removed |= self.prereqs_opt.remove(&coid);
+            removed |= self.coreqs_opt.remove(&coid);
+            self.prereq_grades.remove(&coid);
+
+            removed
+        }
+
         pub(super) fn add_postoption(&mut self, coid: &CourseID) {
             self.post_options.insert(coid.clone());
         }
 
+        /// Returns the courses which list this course as a prerequisite.
+        pub fn post_options(&self) -> &HashSet<CourseID> {
+            &self.post_options
+        }
+
         /// Returns the corequisites for a course.  For each HashSet
         /// in the returned vector, only one course is required.
         pub fn coreq_sets(&self) -> &Vec<HashSet<CourseID>> {
@@ -382,6 +3477,65 @@ pub(crate) mod course {
         pub fn prereq_sets(&self) -> &Vec<HashSet<CourseID>> {
             &self.prereqs
         }
+
+        /// Returns the minimum grade the given prerequisite must be passed
+        /// with, if the course sets one.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use myca::{Course, CourseID, Grade};
+        /// # use serde_json::json;
+        /// #
+        /// let course: Course = serde_json::from_value(json!({
+        /// # "complete": true,
+        /// # "name": "",
+        /// # "description": "",
+        /// # "offered": "",
+        /// # "age_reqs": "",
+        /// # "prereqs_opt": [],
+        /// # "coreqs": [],
+        /// # "coreqs_opt": [],
+        /// # "post_options": [],
+        ///     "coid": "CS 201",
+        ///     "prereqs": [[{ "course": "CS 101", "min_grade": "C" }], ["MATH 101"]]
+        /// })).unwrap();
+        ///
+        /// assert_eq!(course.min_grade(&CourseID::new("CS", 101)), Some(Grade::C));
+        /// assert_eq!(course.min_grade(&CourseID::new("MATH", 101)), None);
+        /// ```
+        pub fn min_grade(&self, prereq: &CourseID) -> Option<Grade> {
+            self.prereq_grades.get(prereq).copied()
+        }
+
+        /// Returns if this course appears directly in one of the
+        /// prerequisite groups of `other`.  Prerequisites of prerequisites
+        /// are not considered; use `Catalog::transitive_prereqs` for those.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use myca::{Catalog, CourseBuilder, CourseID};
+        /// #
+        /// let intro = CourseID::new("CSCI", 1100);
+        /// let data = CourseID::new("CSCI", 1200);
+        /// let calc = CourseID::new("MATH", 1010);
+        ///
+        /// let mut catalog = Catalog::new();
+        /// catalog.add_course(CourseBuilder::new(&intro).build());
+        /// catalog.add_course(CourseBuilder::new(&data).prereq_group(vec![intro.clone()]).build());
+        /// catalog.add_course(CourseBuilder::new(&calc).build());
+        ///
+        /// let intro_course = catalog.get_course(&intro).unwrap();
+        /// assert!(intro_course.is_prereq_of(&catalog, &data));
+        /// assert!(!intro_course.is_prereq_of(&catalog, &calc));
+        /// assert!(!catalog.get_course(&calc).unwrap().is_prereq_of(&catalog, &data));
+        /// ```
+        pub fn is_prereq_of(&self, catalog: &Catalog, other: &CourseID) -> bool {
+            catalog
+                .get_course(other)
+                .is_some_and(|other| other.prereqs.iter().any(|group| group.contains(&self.coid)))
+        }
     }
 
     /// Courses are printed in the following format: "COID: NAME"
@@ -420,4 +3574,111 @@ pub(crate) mod course {
             write!(f, "{}: {}", self.coid, self.name)
         }
     }
+
+    /// Constructs a `Course` without going through deserialization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Course, CourseBuilder, CourseID, CreditRange};
+    /// # use std::collections::HashSet;
+    /// #
+    /// let calc = CourseID::new("MATH", 1010);
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let alt_intro = CourseID::new("CSCI", 1010);
+    ///
+    /// let course = CourseBuilder::new(&CourseID::new("CSCI", 1200))
+    ///     .name("Data Structures")
+    ///     .credits(CreditRange::fixed(4))
+    ///     .offered("Fall/Spring")
+    ///     .prereq_group(vec![intro.clone(), alt_intro.clone()])
+    ///     .prereq_group(vec![calc.clone()])
+    ///     .build();
+    ///
+    /// let expected: Vec<HashSet<CourseID>> = vec![
+    ///     [intro, alt_intro].iter().cloned().collect(),
+    ///     [calc].iter().cloned().collect(),
+    /// ];
+    ///
+    /// assert_eq!(course.prereq_sets(), &expected);
+    /// assert_eq!(format!("{}", course), "CSCI 1200: Data Structures");
+    /// ```
+    pub struct CourseBuilder {
+        course: Course,
+    }
+
+    impl CourseBuilder {
+        /// Starts building a complete course with the given ID.
+        pub fn new(coid: &CourseID) -> Self {
+            CourseBuilder {
+                course: Course::new(coid),
+            }
+        }
+
+        /// Sets the name of the course.
+        pub fn name(mut self, name: &str) -> Self {
+            self.course.name = String::from(name);
+            self
+        }
+
+        /// Sets the description of the course.
+        pub fn description(mut self, description: &str) -> Self {
+            self.course.description = String::from(description);
+            self
+        }
+
+        /// Sets the number of credits the course is worth.
+        pub fn credits(mut self, credits: CreditRange) -> Self {
+            self.course.credits = credits;
+            self
+        }
+
+        /// Sets the seasons the course is offered in, in the same format as
+        /// the catalog's `offered` field.
+        pub fn offered(mut self, offered: &str) -> Self {
+            self.course.offered = String::from(offered);
+            self
+        }
+
+        /// Marks the course as one which may be taken more than once.
+        pub fn repeatable(mut self, repeatable: bool) -> Self {
+            self.course.repeatable = repeatable;
+            self
+        }
+
+        /// Adds an attribute to the course.
+        pub fn tag(mut self, tag: &str) -> Self {
+            self.course.tags.insert(String::from(tag));
+            self
+        }
+
+        /// Adds a course which may not be taken alongside this one.
+        pub fn antireq(mut self, coid: &CourseID) -> Self {
+            self.course.antireqs.insert(coid.clone());
+            self
+        }
+
+        /// Marks the course as one which may be taken in place of another.
+        pub fn substitutes_for(mut self, coid: &CourseID) -> Self {
+            self.course.substitutes_for = Some(coid.clone());
+            self
+        }
+
+        /// Adds a group of prerequisites, only one of which is required.
+        pub fn prereq_group<I: IntoIterator<Item = CourseID>>(mut self, group: I) -> Self {
+            self.course.prereqs.push(group.into_iter().collect());
+            self
+        }
+
+        /// Adds a group of corequisites, only one of which is required.
+        pub fn coreq_group<I: IntoIterator<Item = CourseID>>(mut self, group: I) -> Self {
+            self.course.coreqs.push(group.into_iter().collect());
+            self
+        }
+
+        /// Returns the built course.
+        pub fn build(self) -> Course {
+            self.course
+        }
+    }
 }
@@ -0,0 +1,102 @@
+use std::error;
+use std::fmt;
+
+use crate::catalog::course::{CourseID, ParseCourseIDError};
+use crate::catalog::CatalogError;
+use crate::schedule::{
+    NotOfferedError, ParseSeasonError, ParseSemTimeError, PlacementIssue, ScheduleFileError,
+};
+
+/// Any error produced by this crate.  The more specific error types used
+/// throughout the crate all convert into this one, so callers embedding the
+/// library can handle every failure through a single type.
+#[derive(Debug)]
+pub enum Error {
+    /// A catalog could not be read or is malformed.
+    Catalog(CatalogError),
+    /// A string is not in the CourseID format.
+    ParseCourseID(ParseCourseIDError),
+    /// A string is not in the SemTime format.
+    ParseSemTime(ParseSemTimeError),
+    /// A string is not the name of a season.
+    ParseSeason(ParseSeasonError),
+    /// A course cannot be found in the catalog.
+    CourseNotFound(CourseID),
+    /// A course cannot be placed into a schedule.
+    Placement(PlacementIssue),
+    /// A course is not offered in the season of a semester.
+    NotOffered(NotOfferedError),
+    /// A schedule file could not be read.
+    ScheduleFile(ScheduleFileError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Catalog(err) => write!(f, "{}", err),
+            Error::ParseCourseID(err) => write!(f, "{}", err),
+            Error::ParseSemTime(err) => write!(f, "{}", err),
+            Error::ParseSeason(err) => write!(f, "{}", err),
+            Error::CourseNotFound(coid) => write!(f, "{} is not in the catalog", coid),
+            Error::Placement(issue) => write!(f, "{}", issue),
+            Error::NotOffered(err) => write!(f, "{}", err),
+            Error::ScheduleFile(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Catalog(err) => Some(err),
+            Error::ParseCourseID(err) => Some(err),
+            Error::ParseSemTime(err) => Some(err),
+            Error::ParseSeason(err) => Some(err),
+            Error::NotOffered(err) => Some(err),
+            Error::ScheduleFile(err) => Some(err),
+            Error::CourseNotFound(_) | Error::Placement(_) => None,
+        }
+    }
+}
+
+impl From<CatalogError> for Error {
+    fn from(err: CatalogError) -> Self {
+        Error::Catalog(err)
+    }
+}
+
+impl From<ParseCourseIDError> for Error {
+    fn from(err: ParseCourseIDError) -> Self {
+        Error::ParseCourseID(err)
+    }
+}
+
+impl From<ParseSemTimeError> for Error {
+    fn from(err: ParseSemTimeError) -> Self {
+        Error::ParseSemTime(err)
+    }
+}
+
+impl From<ParseSeasonError> for Error {
+    fn from(err: ParseSeasonError) -> Self {
+        Error::ParseSeason(err)
+    }
+}
+
+impl From<PlacementIssue> for Error {
+    fn from(issue: PlacementIssue) -> Self {
+        Error::Placement(issue)
+    }
+}
+
+impl From<NotOfferedError> for Error {
+    fn from(err: NotOfferedError) -> Self {
+        Error::NotOffered(err)
+    }
+}
+
+impl From<ScheduleFileError> for Error {
+    fn from(err: ScheduleFileError) -> Self {
+        Error::ScheduleFile(err)
+    }
+}
@@ -1,6 +1,10 @@
 mod catalog;
+mod error;
+mod requirement;
 mod schedule;
 
 pub use catalog::course::*;
 pub use catalog::*;
+pub use error::Error;
+pub use requirement::*;
 pub use schedule::*;
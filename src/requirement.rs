@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use super::catalog::course::CourseID;
+use super::catalog::Catalog;
+use super::schedule::Schedule;
+
+/// A degree requirement which a schedule may or may not fulfill.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Requirement {
+    /// Every course in the set must be scheduled.
+    AllOf(HashSet<CourseID>),
+    /// At least one course in the set must be scheduled.
+    OneOf(HashSet<CourseID>),
+    /// The scheduled courses from the pool must be worth at least the given
+    /// number of credits, such as "12 credits of humanities electives".
+    /// Each course counts for its minimum number of credits.
+    CreditsFrom {
+        pool: HashSet<CourseID>,
+        credits: u32,
+    },
+    /// At least `count` scheduled courses must carry the tag, such as "four
+    /// HASS courses".
+    NFromTag { tag: String, count: usize },
+}
+
+impl Requirement {
+    /// Returns if the schedule fulfills the requirement.  The catalog is
+    /// used to look up the credits and tags of each course, and a course
+    /// the catalog considers equivalent to a required one, such as transfer
+    /// credit or an honors variant, counts in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, CreditRange, Requirement, Schedule, Semester, SemTime};
+    /// #
+    /// let pool: Vec<CourseID> = (1100..1103).map(|code| CourseID::new("HUMN", code)).collect();
+    ///
+    /// let mut catalog = Catalog::new();
+    /// for coid in &pool {
+    ///     catalog.add_course(CourseBuilder::new(coid).credits(CreditRange::fixed(4)).build());
+    /// }
+    ///
+    /// let requirement = Requirement::CreditsFrom {
+    ///     pool: pool.iter().cloned().collect(),
+    ///     credits: 8,
+    /// };
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![pool[0].clone()]));
+    /// assert!(!requirement.satisfies(&schedule, &catalog));
+    ///
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Spring(2020), vec![pool[2].clone()]));
+    /// assert!(requirement.satisfies(&schedule, &catalog));
+    ///
+    /// let one_of = Requirement::OneOf(pool.iter().cloned().collect());
+    /// assert!(one_of.satisfies(&schedule, &catalog));
+    ///
+    /// let all_of = Requirement::AllOf(pool.iter().cloned().collect());
+    /// assert!(!all_of.satisfies(&schedule, &catalog));
+    ///
+    /// let two_hass = Requirement::NFromTag {
+    ///     tag: String::from("HASS"),
+    ///     count: 2,
+    /// };
+    /// let philosophy = CourseID::new("PHIL", 1100);
+    /// let economics = CourseID::new("ECON", 1200);
+    /// catalog.add_course(CourseBuilder::new(&philosophy).tag("HASS").build());
+    /// catalog.add_course(CourseBuilder::new(&economics).tag("HASS").build());
+    ///
+    /// schedule.add_course(&SemTime::Fall(2019), &philosophy);
+    /// assert!(!two_hass.satisfies(&schedule, &catalog));
+    ///
+    /// schedule.add_course(&SemTime::Spring(2020), &economics);
+    /// assert!(two_hass.satisfies(&schedule, &catalog));
+    ///
+    /// let writing = CourseID::new("WRIT", 1100);
+    /// let transfer = CourseID::new("XFER", 1100);
+    /// catalog.add_course(CourseBuilder::new(&writing).build());
+    /// catalog.add_equivalency(transfer.clone(), writing.clone());
+    ///
+    /// let needs_writing = Requirement::AllOf(vec![writing].into_iter().collect());
+    /// assert!(!needs_writing.satisfies(&schedule, &catalog));
+    ///
+    /// schedule.add_course(&SemTime::Fall(2019), &transfer);
+    /// assert!(needs_writing.satisfies(&schedule, &catalog));
+    /// ```
+    pub fn satisfies(&self, schedule: &Schedule, catalog: &Catalog) -> bool {
+        match self {
+            Requirement::AllOf(courses) => courses
+                .iter()
+                .all(|coid| schedule.contains_equivalent(coid, catalog)),
+            Requirement::OneOf(courses) => courses
+                .iter()
+                .any(|coid| schedule.contains_equivalent(coid, catalog)),
+            Requirement::CreditsFrom { pool, credits } => {
+                let earned: u32 = pool
+                    .iter()
+                    .filter(|coid| schedule.contains_equivalent(coid, catalog))
+                    .filter_map(|coid| catalog.get_course(coid))
+                    .map(|course| u32::from(course.credits().min()))
+                    .sum();
+
+                earned >= *credits
+            }
+            Requirement::NFromTag { tag, count } => {
+                let tagged = catalog
+                    .courses_with_tag(tag)
+                    .iter()
+                    .filter(|course| schedule.contains_equivalent(&course.get_id(), catalog))
+                    .count();
+
+                tagged >= *count
+            }
+        }
+    }
+}
@@ -1,9 +1,13 @@
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::TryFrom;
+use std::error::Error;
 use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 
-use super::catalog::course::CourseID;
+use super::catalog::course::{Course, CourseID, CreditRange, Grade};
 use super::catalog::Catalog;
 
 /// Used to identify a semester in the schedule.
@@ -14,20 +18,308 @@ pub enum SemTime {
     Summer(i32),
 }
 
-/// Semesters are ordered based on the time they represent (earlier times
-/// are "less" than later times).
-impl Ord for SemTime {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+/// The season of the year a semester takes place in.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Deserialize, Serialize)]
+pub enum Season {
+    Fall,
+    Spring,
+    Summer,
+}
+
+impl Season {
+    /// Every season, in the order they occur within a year.
+    pub const ALL: [Season; 3] = [Season::Spring, Season::Summer, Season::Fall];
+}
+
+/// Seasons are printed by name, as in "Fall".
+///
+/// # Examples
+///
+/// ```
+/// # use myca::Season;
+/// #
+/// for season in &Season::ALL {
+///     assert_eq!(season.to_string().parse::<Season>(), Ok(*season));
+/// }
+///
+/// assert_eq!(Season::Fall.to_string(), "Fall");
+/// assert_eq!(Season::Spring.to_string(), "Spring");
+/// assert_eq!(Season::Summer.to_string(), "Summer");
+/// ```
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Season::Fall => write!(f, "Fall"),
+            Season::Spring => write!(f, "Spring"),
+            Season::Summer => write!(f, "Summer"),
+        }
+    }
+}
+
+/// The error returned when a string is not the name of a season.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ParseSeasonError(String);
+
+impl fmt::Display for ParseSeasonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "'{}' is not a season", self.0)
+    }
+}
+
+impl Error for ParseSeasonError {}
+
+/// Seasons are parsed from their names, ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::Season;
+/// #
+/// assert_eq!("fall".parse::<Season>(), Ok(Season::Fall));
+/// assert_eq!("SPRING".parse::<Season>(), Ok(Season::Spring));
+/// assert_eq!("Summer".parse::<Season>(), Ok(Season::Summer));
+/// assert!("Autumn".parse::<Season>().is_err());
+/// ```
+impl FromStr for Season {
+    type Err = ParseSeasonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Season::ALL
+            .iter()
+            .find(|season| season.to_string().eq_ignore_ascii_case(s))
+            .cloned()
+            .ok_or_else(|| ParseSeasonError(String::from(s)))
+    }
+}
+
+/// Parses the seasons a course is offered in from a catalog's `offered`
+/// string.
+///
+/// The string is split on commas, slashes, and whitespace, and each token
+/// may be a season name ("Fall", "spring", ...) or the compact letter codes
+/// written by `tools/gen_catalog.py` ("f", "s", and "u" for fall, spring,
+/// and summer).  The even/odd year letters ("e" and "o") are accepted but
+/// ignored.  Empty input, or input without any recognized season, is
+/// treated as being offered in every season.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::{parse_offered, Season};
+/// # use std::collections::HashSet;
+/// #
+/// let fall_spring: HashSet<Season> = [Season::Fall, Season::Spring].iter().cloned().collect();
+/// let all: HashSet<Season> = Season::ALL.iter().cloned().collect();
+///
+/// assert_eq!(parse_offered("Fall,Spring"), fall_spring);
+/// assert_eq!(parse_offered("Fall/Spring/Summer"), all);
+/// assert_eq!(parse_offered("sf"), fall_spring);
+/// assert_eq!(parse_offered(""), all);
+/// ```
+pub fn parse_offered(s: &str) -> HashSet<Season> {
+    let mut seasons = HashSet::new();
+
+    for token in s
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .map(str::to_lowercase)
+    {
+        if let Ok(season) = token.parse::<Season>() {
+            seasons.insert(season);
+            continue;
+        }
+
+        match token.as_str() {
+            compact if compact.chars().all(|c| "fsueo".contains(c)) => {
+                for c in compact.chars() {
+                    match c {
+                        'f' => seasons.insert(Season::Fall),
+                        's' => seasons.insert(Season::Spring),
+                        'u' => seasons.insert(Season::Summer),
+                        _ => false,
+                    };
+                }
+            }
+            _ => (),
+        }
+    }
+
+    if seasons.is_empty() {
+        seasons.extend(Season::ALL.iter().cloned());
+    }
+
+    seasons
+}
+
+impl SemTime {
+    /// Returns the season the semester takes place in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Season, SemTime};
+    /// #
+    /// assert_eq!(SemTime::Fall(2020).season(), Season::Fall);
+    /// ```
+    pub fn season(&self) -> Season {
+        match self {
+            SemTime::Fall(_) => Season::Fall,
+            SemTime::Spring(_) => Season::Spring,
+            SemTime::Summer(_) => Season::Summer,
+        }
+    }
+
+    /// Returns the year the semester takes place in.
+    fn year(&self) -> i32 {
+        match *self {
+            SemTime::Fall(year) | SemTime::Spring(year) | SemTime::Summer(year) => year,
+        }
+    }
+
+    /// Returns the semester in the given season of the given year.
+    fn from_season(season: Season, year: i32) -> SemTime {
+        match season {
+            Season::Fall => SemTime::Fall(year),
+            Season::Spring => SemTime::Spring(year),
+            Season::Summer => SemTime::Summer(year),
+        }
+    }
+
+    /// Returns the semester immediately following this one, including
+    /// summer semesters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::SemTime;
+    /// #
+    /// assert_eq!(SemTime::Spring(2020).next(), SemTime::Summer(2020));
+    /// assert_eq!(SemTime::Summer(2020).next(), SemTime::Fall(2020));
+    /// assert_eq!(SemTime::Fall(2020).next(), SemTime::Spring(2021));
+    /// ```
+    pub fn next(&self) -> SemTime {
+        match *self {
+            SemTime::Spring(year) => SemTime::Summer(year),
+            SemTime::Summer(year) => SemTime::Fall(year),
+            SemTime::Fall(year) => SemTime::Spring(year + 1),
+        }
+    }
+
+    /// Returns the number of terms from this semester to `other`, which is
+    /// negative if `other` is earlier.  Summer terms are only counted if
+    /// `include_summer` is set; otherwise a summer semester counts as the
+    /// spring before it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::SemTime;
+    /// #
+    /// assert_eq!(SemTime::Fall(2020).terms_until(&SemTime::Fall(2021), false), 2);
+    /// assert_eq!(SemTime::Fall(2020).terms_until(&SemTime::Fall(2021), true), 3);
+    /// assert_eq!(SemTime::Fall(2021).terms_until(&SemTime::Spring(2021), false), -1);
+    /// assert_eq!(SemTime::Summer(2020).terms_until(&SemTime::Fall(2020), false), 1);
+    /// ```
+    pub fn terms_until(&self, other: &SemTime, include_summer: bool) -> i64 {
+        other.term_index(include_summer) - self.term_index(include_summer)
+    }
+
+    /// Returns the position of the semester in the sequence of all terms.
+    fn term_index(&self, include_summer: bool) -> i64 {
+        let (year, offset) = match *self {
+            SemTime::Spring(year) => (year, 0),
+            SemTime::Summer(year) => (year, if include_summer { 1 } else { 0 }),
+            SemTime::Fall(year) => (year, if include_summer { 2 } else { 1 }),
+        };
+        let per_year = if include_summer { 3 } else { 2 };
+
+        i64::from(year) * per_year + offset
+    }
+
+    /// Returns the semester in the same season, `delta` years later (or
+    /// earlier, if `delta` is negative).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::SemTime;
+    /// #
+    /// assert_eq!(SemTime::Fall(0).shift_years(2024), SemTime::Fall(2024));
+    /// assert_eq!(SemTime::Spring(2020).shift_years(-1), SemTime::Spring(2019));
+    /// ```
+    pub fn shift_years(&self, delta: i32) -> SemTime {
+        match *self {
+            SemTime::Fall(year) => SemTime::Fall(year + delta),
+            SemTime::Spring(year) => SemTime::Spring(year + delta),
+            SemTime::Summer(year) => SemTime::Summer(year + delta),
+        }
+    }
+}
+
+/// SemTimes are printed in the format: "Season Year"
+///
+/// # Examples
+///
+/// ```
+/// # use myca::SemTime;
+/// #
+/// assert_eq!(format!("{}", SemTime::Spring(2020)), "Spring 2020");
+/// ```
+impl fmt::Display for SemTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.season(), self.year())
+    }
+}
+
+/// The error returned when a string is not in the SemTime format.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ParseSemTimeError(String);
+
+impl fmt::Display for ParseSemTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "'{}' is not in the \"Season Year\" format", self.0)
+    }
+}
+
+impl Error for ParseSemTimeError {}
+
+/// SemTimes are parsed from the same "Season Year" format they are printed
+/// in, ignoring the case of the season.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::SemTime;
+/// #
+/// assert_eq!("Fall 2019".parse::<SemTime>(), Ok(SemTime::Fall(2019)));
+/// assert_eq!(SemTime::Summer(0).to_string().parse::<SemTime>(), Ok(SemTime::Summer(0)));
+///
+/// assert!("Autumn 2019".parse::<SemTime>().is_err());
+/// assert!("Fall".parse::<SemTime>().is_err());
+/// ```
+impl FromStr for SemTime {
+    type Err = ParseSemTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseSemTimeError(String::from(s));
+
+        let mut parts = s.split_whitespace();
+        let (season, year) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(season), Some(year), None) => (season, year),
+            _ => return Err(err()),
+        };
+
+        let season = season.parse::<Season>().map_err(|_| err())?;
+        let year = year.parse::<i32>().map_err(|_| err())?;
+        Ok(SemTime::from_season(season, year))
     }
 }
 
 /// Semesters are ordered based on the time they represent (earlier times
 /// are "less" than later times).
-impl PartialOrd for SemTime {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+impl Ord for SemTime {
+    fn cmp(&self, other: &Self) -> Ordering {
         if self == other {
-            return Some(Ordering::Equal);
+            return Ordering::Equal;
         }
 
         let (self_year, self_season) = match self {
@@ -43,21 +335,173 @@ impl PartialOrd for SemTime {
         };
 
         if self_year != other_year {
-            return self_year.partial_cmp(other_year);
+            return self_year.cmp(other_year);
         }
 
-        self_season.partial_cmp(&other_season)
+        self_season.cmp(&other_season)
+    }
+}
+
+/// Semesters are ordered based on the time they represent (earlier times
+/// are "less" than later times).
+impl PartialOrd for SemTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The progress a student has made in a scheduled course.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, Default, Deserialize, Serialize,
+)]
+pub enum CourseStatus {
+    #[default]
+    Planned,
+    InProgress,
+    Completed,
+}
+
+/// The fewest credits a semester may have to be full time.
+pub const FULL_TIME_CREDITS: u32 = 12;
+
+/// The most credits a semester may have before it is an overload.
+pub const OVERLOAD_CREDITS: u32 = 18;
+
+/// How heavy a semester's workload is, as returned by
+/// `Semester::load_category`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, Deserialize, Serialize)]
+pub enum LoadCategory {
+    /// Fewer than `FULL_TIME_CREDITS` credits.
+    Underload,
+    /// Between `FULL_TIME_CREDITS` and `OVERLOAD_CREDITS` credits, inclusive.
+    FullTime,
+    /// More than `OVERLOAD_CREDITS` credits.
+    Overload,
+}
+
+/// The error returned when a course is added to a semester in a season it
+/// is not offered in.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct NotOfferedError {
+    pub course: CourseID,
+    pub season: Season,
+}
+
+impl fmt::Display for NotOfferedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} is not offered in the {}", self.course, self.season)
     }
 }
 
+impl Error for NotOfferedError {}
+
 /// This data structure stores the set of all courses for a given
-/// university semester.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+/// university semester, along with the status of each course and the
+/// grades earned in completed courses.  Courses are always kept sorted, so
+/// semesters display and serialize deterministically.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct Semester {
-    courses: HashSet<CourseID>,
+    #[serde(with = "course_statuses")]
+    courses: BTreeMap<CourseID, CourseStatus>,
+    #[serde(
+        default,
+        with = "course_grades",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    grades: BTreeMap<CourseID, Grade>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    slots: Vec<String>,
     time: SemTime,
 }
 
+/// Serializes a semester's courses as a list of `[course, status]` pairs.
+/// A bare course without a status is also accepted, and is `Planned`.
+mod course_statuses {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::BTreeMap;
+
+    use super::CourseStatus;
+    use crate::catalog::course::CourseID;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Entry {
+        WithStatus(CourseID, CourseStatus),
+        Bare(CourseID),
+    }
+
+    pub fn serialize<S: Serializer>(
+        courses: &BTreeMap<CourseID, CourseStatus>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let entries: Vec<(&CourseID, &CourseStatus)> = courses.iter().collect();
+        entries.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BTreeMap<CourseID, CourseStatus>, D::Error> {
+        let entries = Vec::<Entry>::deserialize(deserializer)?;
+
+        Ok(entries
+            .into_iter()
+            .map(|entry| match entry {
+                Entry::WithStatus(coid, status) => (coid, status),
+                Entry::Bare(coid) => (coid, CourseStatus::Planned),
+            })
+            .collect())
+    }
+}
+
+/// Serializes a semester's grades as a list of `[course, grade]` pairs.
+mod course_grades {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::BTreeMap;
+
+    use crate::catalog::course::{CourseID, Grade};
+
+    pub fn serialize<S: Serializer>(
+        grades: &BTreeMap<CourseID, Grade>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let entries: Vec<(&CourseID, &Grade)> = grades.iter().collect();
+        entries.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BTreeMap<CourseID, Grade>, D::Error> {
+        let entries = Vec::<(CourseID, Grade)>::deserialize(deserializer)?;
+        Ok(entries.into_iter().collect())
+    }
+}
+
+/// Serializes pinned courses as a list of `[course, semester]` pairs, since
+/// course IDs cannot be JSON object keys.
+mod pinned_terms {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::HashMap;
+
+    use super::SemTime;
+    use crate::catalog::course::CourseID;
+
+    pub fn serialize<S: Serializer>(
+        pinned: &HashMap<CourseID, SemTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut entries: Vec<(&CourseID, &SemTime)> = pinned.iter().collect();
+        entries.sort();
+        entries.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<CourseID, SemTime>, D::Error> {
+        let entries = Vec::<(CourseID, SemTime)>::deserialize(deserializer)?;
+        Ok(entries.into_iter().collect())
+    }
+}
+
 impl Semester {
     /// Generates a new semester corresponding to the given time.
     ///
@@ -70,12 +514,39 @@ impl Semester {
     /// ```
     pub fn new(time: SemTime) -> Self {
         Self {
-            courses: HashSet::new(),
+            courses: BTreeMap::new(),
+            grades: BTreeMap::new(),
+            slots: Vec::new(),
             time,
         }
     }
 
-    /// Adds a course to the semester.
+    /// Generates a new semester corresponding to the given time, containing
+    /// the given courses as `Planned`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, Semester, SemTime};
+    /// #
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let calc = CourseID::new("MATH", 1010);
+    ///
+    /// let semester = Semester::new_with_courses(SemTime::Fall(2019), vec![intro.clone(), calc.clone()]);
+    ///
+    /// assert!(semester.contains(&intro));
+    /// assert!(semester.contains(&calc));
+    /// ```
+    pub fn new_with_courses(time: SemTime, courses: impl IntoIterator<Item = CourseID>) -> Self {
+        let mut semester = Semester::new(time);
+        for coid in courses {
+            semester.add_course(&coid);
+        }
+        semester
+    }
+
+    /// Adds a course to the semester.  Newly added courses are `Planned`;
+    /// adding a course which is already in the semester keeps its status.
     ///
     /// # Examples
     ///
@@ -90,32 +561,107 @@ impl Semester {
     /// assert!(semester.contains(&coid));
     /// ```
     pub fn add_course(&mut self, coid: &CourseID) {
-        self.courses.insert(coid.clone());
+        self.courses.entry(coid.clone()).or_default();
     }
 
-    /// Removes a course from the semester.
+    /// Adds a course to the semester only if the catalog lists it as
+    /// offered in the semester's season.  Courses missing from the catalog
+    /// are treated as offered in every season.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use myca::{CourseID, Semester, SemTime};
+    /// # use myca::{Catalog, CourseBuilder, CourseID, Semester, SemTime};
+    /// #
+    /// let coid = CourseID::new("TEST", 1100);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&coid).offered("Fall").build());
+    ///
+    /// let mut spring = Semester::new(SemTime::Spring(2020));
+    /// assert!(spring.add_course_checked(&coid, &catalog).is_err());
+    /// assert!(!spring.contains(&coid));
+    ///
+    /// let mut fall = Semester::new(SemTime::Fall(2020));
+    /// assert!(fall.add_course_checked(&coid, &catalog).is_ok());
+    /// assert!(fall.contains(&coid));
+    /// ```
+    pub fn add_course_checked(
+        &mut self,
+        coid: &CourseID,
+        catalog: &Catalog,
+    ) -> Result<(), NotOfferedError> {
+        if let Some(course) = catalog.get_course(coid) {
+            let season = self.time.season();
+            if !course.offered_seasons().contains(&season) {
+                return Err(NotOfferedError {
+                    course: coid.clone(),
+                    season,
+                });
+            }
+        }
+
+        self.add_course(coid);
+        Ok(())
+    }
+
+    /// Sets the status of a course in the semester, adding the course if it
+    /// is not already present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, CourseStatus, Semester, SemTime};
     /// #
     /// let mut semester = Semester::new(SemTime::Fall(2019));
     /// let coid = CourseID::new("TEST", 1100);
     ///
     /// semester.add_course(&coid);
+    /// assert_eq!(semester.status(&coid), Some(CourseStatus::Planned));
     ///
-    /// assert!(semester.contains(&coid));
+    /// semester.set_status(&coid, CourseStatus::Completed);
+    /// assert_eq!(semester.status(&coid), Some(CourseStatus::Completed));
     ///
-    /// semester.remove_course(&coid);
+    /// assert_eq!(semester.status(&CourseID::new("TEST", 1200)), None);
+    /// ```
+    pub fn set_status(&mut self, coid: &CourseID, status: CourseStatus) {
+        self.courses.insert(coid.clone(), status);
+    }
+
+    /// Returns the status of a course in the semester, or `None` if the
+    /// course is not in the semester.
+    pub fn status(&self, coid: &CourseID) -> Option<CourseStatus> {
+        self.courses.get(coid).cloned()
+    }
+
+    /// Records the grade earned in a course, marking it `Completed` and
+    /// adding it to the semester if it is not already present.
+    ///
+    /// # Examples
     ///
-    /// assert!(!semester.contains(&coid));
     /// ```
-    pub fn remove_course(&mut self, coid: &CourseID) {
-        self.courses.remove(coid);
+    /// # use myca::{CourseID, CourseStatus, Grade, Semester, SemTime};
+    /// #
+    /// let mut semester = Semester::new(SemTime::Fall(2019));
+    /// let coid = CourseID::new("TEST", 1100);
+    ///
+    /// semester.set_grade(&coid, Grade::B);
+    ///
+    /// assert_eq!(semester.grade(&coid), Some(Grade::B));
+    /// assert_eq!(semester.status(&coid), Some(CourseStatus::Completed));
+    /// ```
+    pub fn set_grade(&mut self, coid: &CourseID, grade: Grade) {
+        self.set_status(coid, CourseStatus::Completed);
+        self.grades.insert(coid.clone(), grade);
     }
 
-    /// Returns if a course is in the semester.
+    /// Returns the grade earned in a course, or `None` if no grade has been
+    /// recorded for it in this semester.
+    pub fn grade(&self, coid: &CourseID) -> Option<Grade> {
+        self.grades.get(coid).cloned()
+    }
+
+    /// Removes a course from the semester.
     ///
     /// # Examples
     ///
@@ -128,366 +674,3805 @@ impl Semester {
     /// semester.add_course(&coid);
     ///
     /// assert!(semester.contains(&coid));
+    ///
+    /// semester.remove_course(&coid);
+    ///
+    /// assert!(!semester.contains(&coid));
     /// ```
-    pub fn contains(&self, coid: &CourseID) -> bool {
-        self.courses.contains(coid)
+    pub fn remove_course(&mut self, coid: &CourseID) {
+        self.courses.remove(coid);
+        self.grades.remove(coid);
     }
 
-    /// Returns the SemTime corresponding to the semester.
+    /// Adds a placeholder slot, such as "HASS Elective", to be filled with a
+    /// concrete course later.  A semester may hold several slots with the
+    /// same label.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use myca::{Semester, SemTime};
+    /// # use myca::{CourseID, Semester, SemTime};
     /// #
-    /// let time = SemTime::Fall(2019);
-    /// let semester = Semester::new(time.clone());
+    /// let elective = CourseID::new("PHIL", 1100);
     ///
-    /// assert_eq!(semester.get_time(), &time);
+    /// let mut semester = Semester::new(SemTime::Fall(2019));
+    /// semester.add_slot("HASS Elective");
+    /// semester.add_slot("HASS Elective");
+    /// assert_eq!(semester.slots().len(), 2);
+    ///
+    /// assert!(semester.fill_slot("HASS Elective", &elective));
+    /// assert_eq!(semester.slots(), &["HASS Elective"]);
+    /// assert!(semester.contains(&elective));
+    ///
+    /// assert!(!semester.fill_slot("Free Elective", &CourseID::new("ARTS", 1100)));
+    /// assert_eq!(format!("{}", semester), "Fall 2019:\n\tPHIL 1100\n\t[HASS Elective]\n");
     /// ```
-    pub fn get_time(&self) -> &SemTime {
-        &self.time
+    pub fn add_slot(&mut self, label: &str) {
+        let idx = self.slots.partition_point(|slot| slot.as_str() <= label);
+        self.slots.insert(idx, String::from(label));
     }
-}
 
-/// Formats semester for printing.
-///
-/// # Examples
-///
-/// ```
-/// # use myca::{CourseID, Semester, SemTime};
-/// #
-/// let mut semester = Semester::new(SemTime::Fall(2019));
-/// semester.add_course(&CourseID::new("TEST", 1100));
-///
-/// let semester_expected_output = String::from("Fall 2019:\n\tTEST 1100\n");
-///
-/// let semester_output = format!("{}", semester);
-///
-/// println!("{}", semester_expected_output);
-/// assert_eq!(semester_output, semester_expected_output);
-/// ```
-impl fmt::Display for Semester {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut output = String::new();
+    /// Replaces one slot with the given label by a concrete course.
+    /// Returns false, leaving the semester unchanged, if there is no such
+    /// slot.
+    pub fn fill_slot(&mut self, label: &str, coid: &CourseID) -> bool {
+        match self.slots.iter().position(|slot| slot == label) {
+            Some(idx) => {
+                self.slots.remove(idx);
+                self.add_course(coid);
+                true
+            }
+            None => false,
+        }
+    }
 
-        match self.time {
-            SemTime::Fall(year) => output = format!("{}Fall {}:\n", output, year),
-            SemTime::Spring(year) => output = format!("{}Spring {}:\n", output, year),
-            SemTime::Summer(year) => output = format!("{}Summer {}:\n", output, year),
-        };
+    /// Returns the labels of the unfilled slots in the semester, sorted.
+    pub fn slots(&self) -> &[String] {
+        &self.slots
+    }
 
-        for coid in &self.courses {
-            output = format!("{}\t{}\n", output, coid);
-        }
+    /// Returns the label of the first slot schedule generation would fill
+    /// with the course, which is any slot labelled with one of its tags.
+    fn slot_for(&self, course: &Course) -> Option<&str> {
+        self.slots
+            .iter()
+            .find(|slot| course.tags().contains(*slot))
+            .map(String::as_str)
+    }
 
-        write!(f, "{}", output)
+    /// Returns if the semester contains the course or a course the catalog
+    /// considers equivalent to it.
+    fn contains_equivalent(&self, coid: &CourseID, catalog: &Catalog) -> bool {
+        self.contains(coid)
+            || (catalog.has_equivalencies()
+                && self
+                    .courses
+                    .keys()
+                    .any(|taken| catalog.is_equivalent(taken, coid)))
     }
-}
 
-/// Stores one variant of a set of semesters.
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct Schedule {
-    semesters: BTreeMap<SemTime, Semester>,
-}
+    /// Keeps only the courses for which the predicate returns true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, Semester, SemTime};
+    /// #
+    /// let mut semester = Semester::new(SemTime::Fall(2019));
+    /// let csci = CourseID::new("CSCI", 1100);
+    /// let math = CourseID::new("MATH", 1010);
+    ///
+    /// semester.add_course(&csci);
+    /// semester.add_course(&math);
+    ///
+    /// semester.retain(|coid| coid.subj() == "CSCI");
+    ///
+    /// assert!(semester.contains(&csci));
+    /// assert!(!semester.contains(&math));
+    /// ```
+    pub fn retain<F: FnMut(&CourseID) -> bool>(&mut self, mut f: F) {
+        self.courses.retain(|coid, _| f(coid));
 
-impl Schedule {
-    /// Generates a new schedule.
+        let courses = &self.courses;
+        self.grades.retain(|coid, _| courses.contains_key(coid));
+    }
+
+    /// Returns the number of courses in the semester with the given
+    /// subject.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use myca::Schedule;
+    /// # use myca::{CourseID, Semester, SemTime};
     /// #
-    /// let schedule = Schedule::new();
+    /// let mut semester = Semester::new(SemTime::Fall(2019));
+    /// semester.add_course(&CourseID::new("CSCI", 1100));
+    /// semester.add_course(&CourseID::new("CSCI", 1200));
+    /// semester.add_course(&CourseID::new("MATH", 1010));
+    ///
+    /// assert_eq!(semester.count_subject("CSCI"), 2);
+    /// assert_eq!(semester.count_subject("MATH"), 1);
+    /// assert_eq!(semester.count_subject("PHYS"), 0);
     /// ```
-    pub fn new() -> Self {
-        Self {
-            semesters: BTreeMap::new(),
-        }
+    pub fn count_subject(&self, subj: &str) -> usize {
+        self.courses
+            .keys()
+            .filter(|coid| coid.subj() == subj)
+            .count()
     }
 
-    /// Adds a semester to the schedule.
+    /// Returns if the semester contains any course with the given subject.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use myca::{Schedule, Semester, SemTime};
+    /// # use myca::{CourseID, Semester, SemTime};
     /// #
-    /// let mut schedule = Schedule::new();
-    /// let semester = Semester::new(SemTime::Fall(2019));
+    /// let mut semester = Semester::new(SemTime::Fall(2019));
+    /// semester.add_course(&CourseID::new("CSCI", 1100));
     ///
-    /// schedule.add_semester(semester.clone());
+    /// assert!(semester.contains_subject("CSCI"));
+    /// assert!(!semester.contains_subject("MATH"));
     /// ```
-    pub fn add_semester(&mut self, sem: Semester) {
-        self.semesters.insert(sem.time.clone(), sem);
+    pub fn contains_subject(&self, subj: &str) -> bool {
+        self.courses.keys().any(|coid| coid.subj() == subj)
     }
 
-    /// Adds a course to the schedule at a given time.
+    /// Returns the courses found in both this semester and another.  The
+    /// two semesters do not need to take place at the same time.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// # use myca::{CourseID, Semester, SemTime};
+    /// # use std::collections::HashSet;
     /// #
-    /// let mut schedule = Schedule::new();
-    /// let semester = Semester::new(SemTime::Fall(2019));
+    /// let shared = CourseID::new("TEST", 1100);
+    /// let only_first = CourseID::new("TEST", 1200);
+    /// let only_second = CourseID::new("TEST", 1300);
     ///
-    /// schedule.add_semester(semester);
+    /// let mut first = Semester::new(SemTime::Fall(2019));
+    /// first.add_course(&shared);
+    /// first.add_course(&only_first);
     ///
-    /// assert!(schedule.add_course(&SemTime::Fall(2019), &CourseID::new("TEST", 1100)));
+    /// let mut second = Semester::new(SemTime::Spring(2020));
+    /// second.add_course(&shared);
+    /// second.add_course(&only_second);
+    ///
+    /// let expected: HashSet<CourseID> = vec![shared].into_iter().collect();
+    /// assert_eq!(first.intersection(&second), expected);
     /// ```
-    pub fn add_course(&mut self, sem: &SemTime, coid: &CourseID) -> bool {
-        match self.get_semester_mut(sem) {
-            Some(sem) => {
-                sem.add_course(coid);
-                true
-            }
-            None => false,
-        }
+    pub fn intersection(&self, other: &Semester) -> HashSet<CourseID> {
+        self.courses
+            .keys()
+            .filter(|coid| other.contains(coid))
+            .cloned()
+            .collect()
     }
 
-    /// Returns a reference to the semester corresponding to the given
-    /// SemTime, if it exists.
+    /// Returns the courses found in this semester but not in another.  The
+    /// two semesters do not need to take place at the same time.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use myca::{Schedule, Semester, SemTime};
+    /// # use myca::{CourseID, Semester, SemTime};
+    /// # use std::collections::HashSet;
     /// #
-    /// let mut schedule = Schedule::new();
-    /// let semester = Semester::new(SemTime::Fall(2019));
+    /// let shared = CourseID::new("TEST", 1100);
+    /// let only_first = CourseID::new("TEST", 1200);
+    /// let only_second = CourseID::new("TEST", 1300);
     ///
-    /// schedule.add_semester(semester);
+    /// let mut first = Semester::new(SemTime::Fall(2019));
+    /// first.add_course(&shared);
+    /// first.add_course(&only_first);
     ///
-    /// assert!(schedule.get_semester(&SemTime::Fall(2019)).is_some());
+    /// let mut second = Semester::new(SemTime::Spring(2020));
+    /// second.add_course(&shared);
+    /// second.add_course(&only_second);
+    ///
+    /// let expected: HashSet<CourseID> = vec![only_first].into_iter().collect();
+    /// assert_eq!(first.difference(&second), expected);
     /// ```
-    pub fn get_semester(&self, sem: &SemTime) -> Option<&Semester> {
-        self.semesters.get(sem)
+    pub fn difference(&self, other: &Semester) -> HashSet<CourseID> {
+        self.courses
+            .keys()
+            .filter(|coid| !other.contains(coid))
+            .cloned()
+            .collect()
     }
 
-    /// Returns a mutable reference to the semester corresponding to the
-    /// given SemTime, if it exists.
+    /// Returns if a course is in the semester.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use myca::{Schedule, Semester, SemTime};
+    /// # use myca::{CourseID, Semester, SemTime};
     /// #
-    /// let mut schedule = Schedule::new();
-    /// let semester = Semester::new(SemTime::Fall(2019));
+    /// let mut semester = Semester::new(SemTime::Fall(2019));
+    /// let coid = CourseID::new("TEST", 1100);
     ///
-    /// schedule.add_semester(semester);
+    /// semester.add_course(&coid);
     ///
-    /// assert!(schedule.get_semester_mut(&SemTime::Fall(2019)).is_some());
+    /// assert!(semester.contains(&coid));
     /// ```
-    pub fn get_semester_mut(&mut self, sem: &SemTime) -> Option<&mut Semester> {
-        self.semesters.get_mut(sem)
+    pub fn contains(&self, coid: &CourseID) -> bool {
+        self.courses.contains_key(coid)
     }
 
-    /// Removes a semester from the schedule.
+    /// Returns the minimum and maximum number of credits taken during the
+    /// semester.  Courses which cannot be found in the catalog count for
+    /// zero credits.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use myca::{Schedule, Semester, SemTime};
+    /// # use myca::{Catalog, Course, CourseID, Semester, SemTime};
+    /// # use serde_json::json;
     /// #
-    /// let mut schedule = Schedule::new();
-    /// let semester = Semester::new(SemTime::Fall(2019));
-    ///
-    /// schedule.add_semester(semester);
+    /// fn course(code: u16, credits: serde_json::Value) -> Course {
+    ///     serde_json::from_value(json!({
+    /// #       "complete": true,
+    /// #       "name": "",
+    /// #       "description": "",
+    /// #       "offered": "",
+    /// #       "age_reqs": "",
+    /// #       "prereqs": [],
+    /// #       "prereqs_opt": [],
+    /// #       "coreqs": [],
+    /// #       "coreqs_opt": [],
+    /// #       "post_options": [],
+    ///         "coid": { "subj": "TEST", "code": code },
+    ///         "credits": credits
+    ///     })).unwrap()
+    /// }
     ///
-    /// assert!(schedule.get_semester(&SemTime::Fall(2019)).is_some());
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(course(1100, json!(4)));
+    /// catalog.add_course(course(4980, json!({"min": 1, "max": 4})));
     ///
-    /// schedule.remove_semester(&SemTime::Fall(2019));
+    /// let mut semester = Semester::new(SemTime::Fall(2019));
+    /// semester.add_course(&CourseID::new("TEST", 1100));
+    /// semester.add_course(&CourseID::new("TEST", 4980));
     ///
-    /// assert!(schedule.get_semester(&SemTime::Fall(2019)).is_none());
+    /// assert_eq!(semester.total_credits(&catalog), (5, 8));
     /// ```
-    pub fn remove_semester(&mut self, sem: &SemTime) {
-        self.semesters.remove(sem);
+    pub fn total_credits(&self, catalog: &Catalog) -> (u32, u32) {
+        self.courses
+            .keys()
+            .filter_map(|coid| catalog.get_course(coid))
+            .map(|course| course.credits())
+            .fold((0, 0), |(min, max), credits| {
+                (
+                    min + u32::from(credits.min()),
+                    max + u32::from(credits.max()),
+                )
+            })
     }
 
-    /// Returns a reference to the semesters in the schedule.
+    /// Classifies the semester's workload by its minimum number of credits,
+    /// using the `FULL_TIME_CREDITS` and `OVERLOAD_CREDITS` thresholds.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use myca::{Schedule, Semester, SemTime};
+    /// # use myca::{Catalog, CourseBuilder, CourseID, CreditRange, LoadCategory, Semester, SemTime};
     /// #
-    /// let mut schedule = Schedule::new();
-    /// let sem1 = Semester::new(SemTime::Fall(2019));
-    /// let sem2 = Semester::new(SemTime::Spring(2020));
-    ///
-    /// schedule.add_semester(sem1);
-    /// schedule.add_semester(sem2);
+    /// let mut catalog = Catalog::new();
+    /// let mut category = |credits: u8| {
+    ///     let coid = CourseID::new("TEST", u16::from(credits));
+    ///     catalog.add_course(CourseBuilder::new(&coid).credits(CreditRange::fixed(credits)).build());
+    ///     Semester::new_with_courses(SemTime::Fall(2019), vec![coid]).load_category(&catalog)
+    /// };
     ///
-    /// schedule.semesters();
+    /// assert_eq!(category(11), LoadCategory::Underload);
+    /// assert_eq!(category(12), LoadCategory::FullTime);
+    /// assert_eq!(category(18), LoadCategory::FullTime);
+    /// assert_eq!(category(19), LoadCategory::Overload);
     /// ```
-    pub fn semesters(&self) -> &BTreeMap<SemTime, Semester> {
-        &self.semesters
+    pub fn load_category(&self, catalog: &Catalog) -> LoadCategory {
+        let (credits, _) = self.total_credits(catalog);
+
+        if credits < FULL_TIME_CREDITS {
+            LoadCategory::Underload
+        } else if credits <= OVERLOAD_CREDITS {
+            LoadCategory::FullTime
+        } else {
+            LoadCategory::Overload
+        }
     }
 
-    /// Returns if the given course can be found in the schedule.
+    /// Returns a value which displays the semester like its `Display`
+    /// implementation, but with each course printed as "SUBJ CODE: Name"
+    /// using the catalog.  Courses without a name in the catalog are
+    /// printed as only their ID.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// # use myca::{Catalog, CourseBuilder, CourseID, Semester, SemTime};
     /// #
-    /// let coid = CourseID::new("TEST", 1100);
-    /// let coid2 = CourseID::new("TEST", 1200);
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let stub = CourseID::new("CSCI", 1200);
     ///
-    /// let mut semester = Semester::new(SemTime::Fall(2019));
-    /// semester.add_course(&coid);
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&intro).name("Computer Science I").build());
+    /// catalog.emplace_course(&stub);
     ///
-    /// let mut schedule = Schedule::new();
-    /// schedule.add_semester(semester);
+    /// let semester = Semester::new_with_courses(SemTime::Fall(2019), vec![intro, stub]);
     ///
-    /// assert!(schedule.contains(&coid));
-    /// assert!(!schedule.contains(&coid2));
+    /// assert_eq!(
+    ///     semester.display_with_catalog(&catalog).to_string(),
+    ///     "Fall 2019:\n\tCSCI 1100: Computer Science I\n\tCSCI 1200\n"
+    /// );
     /// ```
-    pub fn contains(&self, coid: &CourseID) -> bool {
-        self.get_time(coid).is_some()
+    pub fn display_with_catalog<'a>(&'a self, catalog: &'a Catalog) -> impl fmt::Display + 'a {
+        SemesterWithCatalog {
+            semester: self,
+            catalog,
+        }
     }
 
-    /// Returns the first semester the given course can be found in within
-    /// the schedule, if such a course exists.
+    /// Returns the SemTime corresponding to the semester.
     ///
     /// # Examples
     ///
-    /// ```rust
-    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// ```
+    /// # use myca::{Semester, SemTime};
     /// #
-    /// let coid = CourseID::new("TEST", 1100);
-    /// let coid2 = CourseID::new("TEST", 1200);
-    ///
-    /// let mut semester = Semester::new(SemTime::Fall(2019));
-    /// semester.add_course(&coid);
-    ///
-    /// let mut schedule = Schedule::new();
-    /// schedule.add_semester(semester);
+    /// let time = SemTime::Fall(2019);
+    /// let semester = Semester::new(time.clone());
     ///
-    /// assert_eq!(schedule.get_time(&coid), Some(&SemTime::Fall(2019)));
-    /// assert!(schedule.get_time(&coid2).is_none());
+    /// assert_eq!(semester.get_time(), &time);
     /// ```
-    pub fn get_time(&self, coid: &CourseID) -> Option<&SemTime> {
-        for (time, semester) in &self.semesters {
-            if semester.contains(coid) {
-                return Some(time);
-            }
+    pub fn get_time(&self) -> &SemTime {
+        &self.time
+    }
+}
+
+/// Adds every course from an iterator to the semester, as with
+/// `Semester::add_course`.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::{CourseID, Semester, SemTime};
+/// #
+/// let courses = vec![CourseID::new("CSCI", 1100), CourseID::new("MATH", 1010)];
+///
+/// let mut semester = Semester::new(SemTime::Fall(2019));
+/// semester.extend(courses.clone());
+///
+/// assert!(courses.iter().all(|coid| semester.contains(coid)));
+///
+/// let mut borrowed = Semester::new(SemTime::Fall(2019));
+/// borrowed.extend(&courses);
+///
+/// assert_eq!(borrowed, semester);
+/// ```
+impl Extend<CourseID> for Semester {
+    fn extend<I: IntoIterator<Item = CourseID>>(&mut self, iter: I) {
+        for coid in iter {
+            self.add_course(&coid);
         }
+    }
+}
 
-        None
+impl<'a> Extend<&'a CourseID> for Semester {
+    fn extend<I: IntoIterator<Item = &'a CourseID>>(&mut self, iter: I) {
+        for coid in iter {
+            self.add_course(coid);
+        }
     }
+}
 
-    fn try_add(&self, coid: &CourseID, sem: &SemTime, catalog: &Catalog) -> Option<Self> {
-        if self.contains(coid) {
-            return Some(self.clone());
+/// Formats semester for printing.  Courses are listed in sorted order.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::{CourseID, Semester, SemTime};
+/// #
+/// let mut semester = Semester::new(SemTime::Fall(2019));
+/// semester.add_course(&CourseID::new("TEST", 1100));
+///
+/// let semester_expected_output = String::from("Fall 2019:\n\tTEST 1100\n");
+///
+/// let semester_output = format!("{}", semester);
+///
+/// println!("{}", semester_expected_output);
+/// assert_eq!(semester_output, semester_expected_output);
+///
+/// let mut semester = Semester::new(SemTime::Spring(2020));
+/// semester.add_course(&CourseID::new("MATH", 1010));
+/// semester.add_course(&CourseID::new("CSCI", 1200));
+/// semester.add_course(&CourseID::new("CSCI", 1100));
+///
+/// assert_eq!(
+///     format!("{}", semester),
+///     "Spring 2020:\n\tCSCI 1100\n\tCSCI 1200\n\tMATH 1010\n"
+/// );
+/// ```
+impl fmt::Display for Semester {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut output = String::new();
+
+        output = format!("{}{}:\n", output, self.time);
+
+        for coid in self.courses.keys() {
+            output = format!("{}\t{}\n", output, coid);
         }
 
-        let course = catalog.get_course(coid).unwrap();
+        for slot in &self.slots {
+            output = format!("{}\t[{}]\n", output, slot);
+        }
 
-        for coreq_set in course.coreq_sets() {
-            let mut contains_at_least_one = false;
-            let mut valid_time = false;
-            for coreq in coreq_set {
-                match self.get_time(coreq) {
-                    Some(time) => {
-                        contains_at_least_one = true;
-                        if time == sem {
-                            valid_time = true;
-                            break;
-                        }
-                    }
-                    None => continue,
-                };
-            }
+        write!(f, "{}", output)
+    }
+}
 
-            if contains_at_least_one != valid_time {
-                return None;
+/// Displays a semester with course names, as returned by
+/// `Semester::display_with_catalog`.
+struct SemesterWithCatalog<'a> {
+    semester: &'a Semester,
+    catalog: &'a Catalog,
+}
+
+impl fmt::Display for SemesterWithCatalog<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}:", self.semester.time)?;
+
+        for coid in self.semester.courses.keys() {
+            match self.catalog.get_course(coid) {
+                Some(course) if !course.name().is_empty() => writeln!(f, "\t{}", course)?,
+                _ => writeln!(f, "\t{}", coid)?,
             }
         }
 
-        for prereq_set in course.prereq_sets() {
-            if !prereq_set.iter().any(|prereq| {
-                self.semesters
-                    .iter()
-                    .filter(|(time, _)| time < &sem)
-                    .any(|(_, semester)| semester.contains(prereq))
-            }) {
-                return None;
-            }
+        for slot in &self.semester.slots {
+            writeln!(f, "\t[{}]", slot)?;
         }
 
-        let mut new_sched = self.clone();
-        new_sched.add_course(sem, coid);
-        Some(new_sched)
+        Ok(())
     }
+}
 
-    /// Generates all possible schedules which can be created by adding the
-    /// given course into the schedule.
-    ///
-    /// Recursively adds prerequisites and corequisites based on the catalog
-    /// entry.
-    pub fn add_course_to_schedule(
-        coid: &CourseID,
-        sched: &Schedule,
+/// A reason a course cannot be placed into a semester.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum PlacementIssue {
+    /// The course cannot be found in the catalog.
+    NotInCatalog(CourseID),
+    /// The schedule has no semester at the requested time.
+    NoSuchSemester(SemTime),
+    /// None of the options for one of the course's prerequisite groups are
+    /// scheduled before the requested semester.
+    PrereqNotMet {
+        course: CourseID,
+        options: Vec<CourseID>,
+    },
+    /// The course has been forbidden from the requested semester.
+    Forbidden { course: CourseID, time: SemTime },
+    /// One of the course's corequisite groups is scheduled, but not in the
+    /// requested semester.
+    CoreqNotConcurrent {
+        course: CourseID,
+        options: Vec<CourseID>,
+    },
+    /// The semester has no unfilled slot with the given label.
+    NoSuchSlot { time: SemTime, label: String },
+    /// The course is pinned to a different semester.
+    Pinned { course: CourseID, pinned: SemTime },
+    /// The requested semester has already reached the configured credit or
+    /// course cap.
+    SemesterFull { course: CourseID, time: SemTime },
+    /// The requested semester is empty, and the schedule already uses the
+    /// configured maximum number of terms.
+    TooManyTerms { course: CourseID, time: SemTime },
+    /// The course is already taken in the given semester.
+    AlreadyInSemester { course: CourseID, time: SemTime },
+    /// The semester already contains a course which may not be taken
+    /// alongside the course.
+    Conflict { course: CourseID, other: CourseID },
+    /// The schedule already contains a course which may not be taken for
+    /// credit along with the course, in any semester.
+    Antirequisite { course: CourseID, other: CourseID },
+    /// A prerequisite of the course was passed with a lower grade than the
+    /// course requires.
+    GradeNotMet {
+        course: CourseID,
+        prereq: CourseID,
+        required: Grade,
+        earned: Grade,
+    },
+}
+
+impl fmt::Display for PlacementIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn join(coids: &[CourseID]) -> String {
+            coids
+                .iter()
+                .map(|coid| coid.to_string())
+                .collect::<Vec<String>>()
+                .join(" or ")
+        }
+
+        match self {
+            PlacementIssue::NotInCatalog(coid) => write!(f, "{} is not in the catalog", coid),
+            PlacementIssue::NoSuchSemester(time) => write!(f, "{} is not in the schedule", time),
+            PlacementIssue::Forbidden { course, time } => {
+                write!(f, "{} may not be taken in {}", course, time)
+            }
+            PlacementIssue::PrereqNotMet { course, options } => write!(
+                f,
+                "{} requires {} to be taken in an earlier semester",
+                course,
+                join(options)
+            ),
+            PlacementIssue::CoreqNotConcurrent { course, options } => write!(
+                f,
+                "{} must be taken in the same semester as {}",
+                course,
+                join(options)
+            ),
+            PlacementIssue::NoSuchSlot { time, label } => {
+                write!(f, "{} has no unfilled {} slot", time, label)
+            }
+            PlacementIssue::Pinned { course, pinned } => {
+                write!(f, "{} must be taken in {}", course, pinned)
+            }
+            PlacementIssue::SemesterFull { course, time } => {
+                write!(f, "{} has no room left for {}", time, course)
+            }
+            PlacementIssue::TooManyTerms { course, time } => write!(
+                f,
+                "{} cannot be taken in {} without using too many terms",
+                course, time
+            ),
+            PlacementIssue::AlreadyInSemester { course, time } => {
+                write!(f, "{} is already taken in {}", course, time)
+            }
+            PlacementIssue::Conflict { course, other } => write!(
+                f,
+                "{} may not be taken in the same semester as {}",
+                course, other
+            ),
+            PlacementIssue::Antirequisite { course, other } => write!(
+                f,
+                "{} may not be taken for credit along with {}",
+                course, other
+            ),
+            PlacementIssue::GradeNotMet {
+                course,
+                prereq,
+                required,
+                earned,
+            } => write!(
+                f,
+                "{} requires a {} or better in {}, but a {} was earned",
+                course, required, prereq, earned
+            ),
+        }
+    }
+}
+
+fn is_repeatable(coid: &CourseID, catalog: &Catalog) -> bool {
+    catalog
+        .get_course(coid)
+        .is_some_and(|course| course.is_repeatable())
+}
+
+/// Records a course which best effort generation could not place, unless it
+/// was already recorded.
+fn record_unplaced(unplaced: &mut Vec<CourseID>, coid: &CourseID) {
+    if !unplaced.contains(coid) {
+        unplaced.push(coid.clone());
+    }
+}
+
+fn sorted(coids: &HashSet<CourseID>) -> Vec<CourseID> {
+    let mut coids: Vec<CourseID> = coids.iter().cloned().collect();
+    coids.sort();
+    coids
+}
+
+/// Which requisites of a course a `LazyTask::Choose` is placing.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum GenerationStage {
+    Prereqs,
+    Coreqs,
+}
+
+/// A unit of pending work for lazy schedule generation.
+#[derive(Clone, Copy)]
+enum LazyTask<'a> {
+    /// Add a course along with all of its requisites.
+    Require(&'a CourseID),
+    /// Add one course from a requisite set of the given course.
+    Choose {
+        coid: &'a CourseID,
+        set: &'a HashSet<CourseID>,
+        stage: GenerationStage,
+    },
+    /// Place the course itself into a semester.
+    Place(&'a CourseID),
+    /// Note that a course of a requisite set was added, for best effort
+    /// generation.
+    Satisfied(usize),
+    /// Continue without satisfying the requisite set, for best effort
+    /// generation, unless a course of it was added on another branch.
+    Unmet {
+        set: &'a HashSet<CourseID>,
+        flag: usize,
+    },
+}
+
+/// Generates schedules one at a time by exploring the choices made during
+/// generation depth first, keeping only the partial schedules along the
+/// current branch in memory.  Returned by
+/// `Schedule::add_course_to_schedule_iter`, and collected by
+/// `Schedule::generate`.
+pub struct LazyGeneration<'a> {
+    catalog: &'a Catalog,
+    config: &'a GenerationConfig,
+    /// The course being generated.
+    root: &'a CourseID,
+    deadline: Option<Instant>,
+    /// Partial schedules, each with the tasks left to finish it and the
+    /// courses best effort generation has left out of it.  The next task is
+    /// at the end of each list.
+    stack: Vec<(Schedule, Vec<LazyTask<'a>>, Vec<CourseID>)>,
+    /// Whether each requisite set chosen from under best effort generation
+    /// has had a course added, indexed by the flag of its tasks.
+    satisfied: Vec<bool>,
+    /// Whether the search was cut short by the deadline.
+    timed_out: bool,
+    /// The number of schedules built by placing a course into a semester.
+    placements: usize,
+}
+
+impl<'a> Iterator for LazyGeneration<'a> {
+    type Item = Schedule;
+
+    fn next(&mut self) -> Option<Schedule> {
+        self.next_plan().map(|(sched, _)| sched)
+    }
+}
+
+impl<'a> LazyGeneration<'a> {
+    /// Finds the next schedule, along with the courses best effort
+    /// generation left out of it.
+    fn next_plan(&mut self) -> Option<(Schedule, Vec<CourseID>)> {
+        while let Some((sched, mut tasks, mut unplaced)) = self.stack.pop() {
+            if self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+            {
+                self.stack.clear();
+                self.timed_out = true;
+                return None;
+            }
+
+            let task = match tasks.pop() {
+                Some(task) => task,
+                None => {
+                    if self.meets_credit_floor(&sched) {
+                        return Some((sched, unplaced));
+                    }
+                    continue;
+                }
+            };
+
+            // Branches are pushed in reverse so they are explored in order
+            match task {
+                LazyTask::Require(coid) => {
+                    if self.config.has_completed(coid, self.catalog) {
+                        self.stack.push((sched, tasks, unplaced));
+                        continue;
+                    }
+
+                    let course = match self.catalog.get_course(coid) {
+                        Some(course) => course,
+                        None => {
+                            if self.config.best_effort && coid == self.root {
+                                record_unplaced(&mut unplaced, coid);
+                                self.stack.push((sched, tasks, unplaced));
+                            }
+                            continue;
+                        }
+                    };
+
+                    for set in course.coreq_sets().iter().rev() {
+                        tasks.push(LazyTask::Choose {
+                            coid,
+                            set,
+                            stage: GenerationStage::Coreqs,
+                        });
+                    }
+                    tasks.push(LazyTask::Place(coid));
+                    for set in course.prereq_sets().iter().rev() {
+                        if !self.config.is_group_waived(coid, set) {
+                            tasks.push(LazyTask::Choose {
+                                coid,
+                                set,
+                                stage: GenerationStage::Prereqs,
+                            });
+                        }
+                    }
+
+                    self.stack.push((sched, tasks, unplaced));
+                }
+                LazyTask::Choose { coid, set, stage } => {
+                    // The set is only left unmet once every branch below
+                    // has been explored without adding any of its courses
+                    let flag = if self.config.best_effort {
+                        let flag = self.satisfied.len();
+                        self.satisfied.push(false);
+
+                        let mut fallback = tasks.clone();
+                        fallback.push(LazyTask::Unmet { set, flag });
+                        self.stack.push((sched.clone(), fallback, unplaced.clone()));
+                        Some(flag)
+                    } else {
+                        None
+                    };
+
+                    // Members are tried in sorted order, so the schedules
+                    // do not depend on the set's iteration order
+                    let mut members: Vec<&CourseID> = set.iter().collect();
+                    members.sort();
+                    for member in members.into_iter().rev() {
+                        // A course can never be its own prerequisite
+                        if stage == GenerationStage::Prereqs && member == coid {
+                            continue;
+                        }
+
+                        let mut branch = tasks.clone();
+                        if let Some(flag) = flag {
+                            branch.push(LazyTask::Satisfied(flag));
+                        }
+                        if !sched.contains_equivalent(member, self.catalog) {
+                            branch.push(LazyTask::Require(member));
+                        }
+                        self.stack.push((sched.clone(), branch, unplaced.clone()));
+                    }
+                }
+                LazyTask::Place(coid) => {
+                    let mut placed = Vec::new();
+                    let mut summer = Vec::new();
+                    for time in sched.semesters().keys() {
+                        if let Some(new_sched) =
+                            sched.try_add(coid, time, self.catalog, self.config)
+                        {
+                            self.placements += 1;
+                            if self.config.summer == SummerPolicy::OnlyIfNeeded
+                                && time.season() == Season::Summer
+                            {
+                                summer.push(new_sched);
+                            } else {
+                                placed.push(new_sched);
+                            }
+                        }
+                    }
+
+                    // Summer semesters are only a fallback
+                    if placed.is_empty() {
+                        placed = summer;
+                    }
+
+                    if placed.is_empty() && self.config.best_effort && coid == self.root {
+                        record_unplaced(&mut unplaced, coid);
+                        placed.push(sched);
+                    }
+
+                    for new_sched in placed.into_iter().rev() {
+                        self.stack
+                            .push((new_sched, tasks.clone(), unplaced.clone()));
+                    }
+                }
+                LazyTask::Satisfied(flag) => {
+                    self.satisfied[flag] = true;
+                    self.stack.push((sched, tasks, unplaced));
+                }
+                LazyTask::Unmet { set, flag } => {
+                    if !self.satisfied[flag] {
+                        for option in sorted(set) {
+                            record_unplaced(&mut unplaced, &option);
+                        }
+                        self.stack.push((sched, tasks, unplaced));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn new(
+        coid: &'a CourseID,
+        sched: &Schedule,
+        catalog: &'a Catalog,
+        config: &'a GenerationConfig,
+    ) -> Self {
+        LazyGeneration {
+            catalog,
+            config,
+            root: coid,
+            deadline: config.timeout.map(|timeout| Instant::now() + timeout),
+            stack: vec![(sched.clone(), vec![LazyTask::Require(coid)], Vec::new())],
+            satisfied: Vec::new(),
+            timed_out: false,
+            placements: 0,
+        }
+    }
+
+    /// Returns the number of times a course has been placed into a semester
+    /// so far, counting every partial schedule built along the way.
+    pub fn placements(&self) -> usize {
+        self.placements
+    }
+
+    fn meets_credit_floor(&self, sched: &Schedule) -> bool {
+        match self.config.min_credits_per_semester {
+            Some(floor) => sched.semesters.values().all(|semester| {
+                semester.courses.is_empty() || semester.total_credits(self.catalog).0 >= floor
+            }),
+            None => true,
+        }
+    }
+}
+
+/// Whether schedule generation may place courses in summer semesters.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::{Catalog, CourseID, GenerationConfig, Schedule, Semester, SemTime, SummerPolicy};
+/// #
+/// let prereq = CourseID::new("TEST", 1100);
+/// let coid = CourseID::new("TEST", 1200);
+///
+/// let mut catalog = Catalog::new();
+/// catalog.emplace_course(&prereq);
+/// catalog.emplace_course(&coid);
+/// catalog.get_course_mut(&coid).unwrap().add_prereq(&prereq);
+///
+/// let mut schedule = Schedule::new();
+/// schedule.add_semester(Semester::new(SemTime::Spring(2020)));
+/// schedule.add_semester(Semester::new(SemTime::Summer(2020)));
+///
+/// let mut config = GenerationConfig::default();
+/// config.summer = SummerPolicy::Allow;
+/// let allowed = Schedule::add_course_to_schedule_with_config(&coid, &schedule, &catalog, &config);
+/// assert!(allowed
+///     .iter()
+///     .any(|schedule| schedule.get_time(&coid) == Some(&SemTime::Summer(2020))));
+///
+/// config.summer = SummerPolicy::Forbid;
+/// let forbidden = Schedule::add_course_to_schedule_with_config(&coid, &schedule, &catalog, &config);
+/// assert!(forbidden.is_empty());
+///
+/// // With a fall semester available, summer is no longer needed
+/// schedule.add_semester(Semester::new(SemTime::Fall(2020)));
+/// config.summer = SummerPolicy::OnlyIfNeeded;
+/// let needed = Schedule::add_course_to_schedule_with_config(&coid, &schedule, &catalog, &config);
+/// assert!(!needed.is_empty());
+/// assert!(needed
+///     .iter()
+///     .all(|schedule| schedule.get_time(&coid) == Some(&SemTime::Fall(2020))));
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub enum SummerPolicy {
+    /// Summer semesters are treated like any other semester.
+    #[default]
+    Allow,
+    /// Courses are never placed in summer semesters.
+    Forbid,
+    /// Courses are placed in summer semesters only when no other semester
+    /// can take them.
+    OnlyIfNeeded,
+}
+
+/// Options which control how schedules are generated.  Options missing
+/// from serialized configurations take their default values.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct GenerationConfig {
+    /// Courses which have already been completed, such as transfer credit.
+    /// These satisfy prerequisites and corequisites as if they were taken
+    /// before the first semester, and are never added to a schedule.
+    pub completed: HashSet<CourseID>,
+    /// Prerequisites which have been waived, as (course, prerequisite)
+    /// pairs.  A waiver only removes that specific prerequisite from that
+    /// specific course: a prerequisite group is skipped only once every
+    /// course in it has been waived, and the waived course may still be
+    /// required by other courses.
+    pub waived_prereqs: HashSet<(CourseID, CourseID)>,
+    /// Semesters specific courses may not be placed in, as (course,
+    /// semester) pairs.
+    pub forbidden: HashSet<(CourseID, SemTime)>,
+    /// Semesters specific courses must be placed in, such as a co-op term
+    /// or a one-time offering.  A pinned course is never placed in any
+    /// other semester, so a schedule whose prerequisites cannot be met by
+    /// its pinned semester is not generated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let prereq = CourseID::new("TEST", 1100);
+    /// let coop = CourseID::new("TEST", 2900);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.emplace_course(&prereq);
+    /// catalog.emplace_course(&coop);
+    /// catalog.get_course_mut(&coop).unwrap().add_prereq(&prereq);
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2024)));
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2025)));
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2025)));
+    ///
+    /// let mut config = GenerationConfig::default();
+    /// config.pinned.insert(coop.clone(), SemTime::Spring(2025));
+    ///
+    /// let schedules = Schedule::add_course_to_schedule_with_config(&coop, &skeleton, &catalog, &config);
+    /// assert!(!schedules.is_empty());
+    /// assert!(schedules
+    ///     .iter()
+    ///     .all(|schedule| schedule.get_time(&coop) == Some(&SemTime::Spring(2025))));
+    ///
+    /// // The prerequisite cannot be taken before Fall 2024
+    /// config.pinned.insert(coop.clone(), SemTime::Fall(2024));
+    /// assert!(Schedule::add_course_to_schedule_with_config(&coop, &skeleton, &catalog, &config).is_empty());
+    /// ```
+    #[serde(with = "pinned_terms")]
+    pub pinned: HashMap<CourseID, SemTime>,
+    /// Whether courses may be placed in summer semesters.
+    pub summer: SummerPolicy,
+    /// If set, prerequisites may be taken in the same semester as the
+    /// courses which require them, rather than strictly before.  A course
+    /// still never satisfies its own prerequisite.
+    pub default_concurrent: bool,
+    /// The fewest credits each semester containing courses may have, as
+    /// required for full-time status.  Unlike the other options this is
+    /// checked once generation has finished, discarding any schedule with
+    /// a non-empty semester whose minimum credits fall below the floor.
+    pub min_credits_per_semester: Option<u32>,
+    /// The most credits a semester may hold.  A course is only placed into
+    /// a semester if the semester's maximum credits, including the course's
+    /// own maximum and `slot_credits` for each unfilled slot, stay within
+    /// the cap.
+    pub max_credits_per_semester: Option<u32>,
+    /// The most courses a semester may hold, counting each unfilled slot
+    /// as a course.
+    pub max_courses_per_semester: Option<usize>,
+    /// The credits each unfilled slot is expected to be worth when checking
+    /// `max_credits_per_semester`.
+    ///
+    /// Generation fills a slot with any course tagged with the slot's label,
+    /// so the course takes the slot's place rather than adding to the
+    /// semester's load.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, CreditRange, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let ethics = CourseID::new("PHIL", 1100);
+    /// let calc = CourseID::new("MATH", 1010);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&intro).credits(CreditRange::fixed(4)).build());
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&ethics)
+    ///         .credits(CreditRange::fixed(4))
+    ///         .tag("HASS Elective")
+    ///         .build(),
+    /// );
+    /// catalog.add_course(CourseBuilder::new(&calc).credits(CreditRange::fixed(4)).build());
+    ///
+    /// let mut fall = Semester::new(SemTime::Fall(2019));
+    /// fall.add_course(&intro);
+    /// fall.add_slot("HASS Elective");
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(fall);
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2020)));
+    ///
+    /// let mut config = GenerationConfig::default();
+    /// config.max_courses_per_semester = Some(2);
+    ///
+    /// // The elective fills the slot, keeping the fall at two courses
+    /// let schedules = Schedule::add_course_to_schedule_with_config(&ethics, &skeleton, &catalog, &config);
+    /// let filled = &schedules[0];
+    /// assert_eq!(filled.get_time(&ethics), Some(&SemTime::Fall(2019)));
+    /// assert!(filled.get_semester(&SemTime::Fall(2019)).unwrap().slots().is_empty());
+    ///
+    /// // Other courses do not fit alongside the slot
+    /// let schedules = Schedule::add_course_to_schedule_with_config(&calc, &skeleton, &catalog, &config);
+    /// assert_eq!(schedules.len(), 1);
+    /// assert_eq!(schedules[0].get_time(&calc), Some(&SemTime::Spring(2020)));
+    ///
+    /// // Nor do they once the slot's credits reach the credit cap
+    /// let mut config = GenerationConfig::default();
+    /// config.max_credits_per_semester = Some(8);
+    /// config.slot_credits = 4;
+    /// let schedules = Schedule::add_course_to_schedule_with_config(&calc, &skeleton, &catalog, &config);
+    /// assert_eq!(schedules.len(), 1);
+    /// assert_eq!(schedules[0].get_time(&calc), Some(&SemTime::Spring(2020)));
+    /// ```
+    pub slot_credits: u32,
+    /// The most semesters containing courses a schedule may have.  Once a
+    /// schedule uses this many terms, courses are only placed into the
+    /// semesters which already contain courses, so longer schedules are
+    /// never built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let first = CourseID::new("TEST", 1100);
+    /// let second = CourseID::new("TEST", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&first).build());
+    /// catalog.add_course(CourseBuilder::new(&second).prereq_group(vec![first.clone()]).build());
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2020)));
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2020)));
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2021)));
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2021)));
+    /// skeleton.add_course(&SemTime::Fall(2019), &CourseID::new("TEST", 1000));
+    /// skeleton.add_course(&SemTime::Spring(2020), &CourseID::new("TEST", 1010));
+    /// skeleton.add_course(&SemTime::Fall(2020), &CourseID::new("TEST", 1020));
+    ///
+    /// let mut config = GenerationConfig::default();
+    /// config.max_terms = Some(4);
+    ///
+    /// let schedules = Schedule::add_course_to_schedule_with_config(&second, &skeleton, &catalog, &config);
+    /// assert!(!schedules.is_empty());
+    /// for schedule in &schedules {
+    ///     assert!(schedule.cost(&catalog).terms <= 4);
+    /// }
+    /// ```
+    pub max_terms: Option<usize>,
+    /// If set, `Schedule::generate` keeps going when a course cannot be
+    /// placed anywhere, leaving it out of the schedule and listing it in that
+    /// schedule's entry of `GenerationResult::unplaced`, rather than
+    /// discarding the whole branch.  When none of the options of a requisite group can be
+    /// placed, every option is listed.  Courses which require an unplaced
+    /// course are usually left unplaced as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let intro = CourseID::new("TEST", 1100);
+    /// let seminar = CourseID::new("TEST", 1900);
+    /// let capstone = CourseID::new("TEST", 4000);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&intro).build());
+    /// catalog.add_course(CourseBuilder::new(&seminar).build());
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&capstone)
+    ///         .prereq_group(vec![intro.clone()])
+    ///         .prereq_group(vec![seminar.clone()])
+    ///         .build(),
+    /// );
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2020)));
+    ///
+    /// // The seminar cannot be taken in any semester
+    /// let mut config = GenerationConfig::default();
+    /// config.forbidden.insert((seminar.clone(), SemTime::Fall(2019)));
+    /// config.forbidden.insert((seminar.clone(), SemTime::Spring(2020)));
+    ///
+    /// let result = Schedule::generate(&capstone, &skeleton, &catalog, &config);
+    /// assert!(result.schedules.is_empty());
+    ///
+    /// config.best_effort = true;
+    /// let result = Schedule::generate(&capstone, &skeleton, &catalog, &config);
+    ///
+    /// assert!(!result.schedules.is_empty());
+    /// assert!(result.schedules.iter().all(|schedule| schedule.contains(&intro)));
+    /// assert!(result.schedules.iter().all(|schedule| !schedule.contains(&seminar)));
+    /// for unplaced in &result.unplaced {
+    ///     assert_eq!(unplaced, &vec![seminar.clone(), capstone.clone()]);
+    /// }
+    /// ```
+    ///
+    /// Each schedule only lists the courses left out of it, so a course
+    /// placed along one choice of requisites is not reported for the
+    /// schedules built from that choice:
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let late = CourseID::new("TEST", 1100);
+    /// let early = CourseID::new("TEST", 1200);
+    /// let goal = CourseID::new("TEST", 2000);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&late).build());
+    /// catalog.add_course(CourseBuilder::new(&early).build());
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&goal)
+    ///         .prereq_group(vec![late.clone(), early.clone()])
+    ///         .build(),
+    /// );
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2020)));
+    ///
+    /// // Taking the first prerequisite leaves no semester for the goal
+    /// let mut config = GenerationConfig::default();
+    /// config.best_effort = true;
+    /// config.forbidden.insert((late.clone(), SemTime::Fall(2019)));
+    ///
+    /// let result = Schedule::generate(&goal, &skeleton, &catalog, &config);
+    ///
+    /// assert!(result.schedules.iter().any(|schedule| schedule.contains(&goal)));
+    /// assert!(result.schedules.iter().any(|schedule| !schedule.contains(&goal)));
+    /// for (schedule, unplaced) in result.schedules.iter().zip(&result.unplaced) {
+    ///     if schedule.contains(&goal) {
+    ///         assert!(unplaced.is_empty());
+    ///     } else {
+    ///         assert_eq!(unplaced, &vec![goal.clone()]);
+    ///     }
+    /// }
+    /// ```
+    pub best_effort: bool,
+    /// How long generation may run before giving up.
+    pub timeout: Option<Duration>,
+    /// Pairs of courses which may not be taken in the same semester.  The
+    /// order of each pair does not matter.
+    pub no_cooccur: Vec<(CourseID, CourseID)>,
+}
+
+/// The outcome of `Schedule::generate`.
+#[derive(Clone, Debug, Default)]
+pub struct GenerationResult {
+    /// Every schedule which was generated.
+    pub schedules: Vec<Schedule>,
+    /// Whether generation stopped early because the timeout elapsed.
+    pub timed_out: bool,
+    /// The courses left out of each schedule, in the order they were found,
+    /// when `GenerationConfig::best_effort` is set.  `unplaced[i]` lists
+    /// the courses missing from `schedules[i]`, and is empty for a complete
+    /// schedule.
+    pub unplaced: Vec<Vec<CourseID>>,
+}
+
+impl GenerationConfig {
+    /// Returns if the course, or a course the catalog considers equivalent
+    /// to it, has already been completed.
+    fn has_completed(&self, coid: &CourseID, catalog: &Catalog) -> bool {
+        self.completed.contains(coid)
+            || (catalog.has_equivalencies()
+                && self
+                    .completed
+                    .iter()
+                    .any(|taken| catalog.is_equivalent(taken, coid)))
+    }
+
+    /// Returns if every prerequisite in the group has been waived for the
+    /// given course.
+    fn is_group_waived(&self, coid: &CourseID, group: &HashSet<CourseID>) -> bool {
+        !self.waived_prereqs.is_empty()
+            && group.iter().all(|prereq| {
+                self.waived_prereqs
+                    .contains(&(coid.clone(), prereq.clone()))
+            })
+    }
+}
+
+/// Stores one variant of a set of semesters.
+///
+/// Schedules can be compared and hashed, allowing them to be used as map
+/// keys.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::{CourseID, Schedule, Semester, SemTime};
+/// # use std::collections::HashSet;
+/// #
+/// let mut sem1 = Semester::new(SemTime::Fall(2019));
+/// sem1.add_course(&CourseID::new("TEST", 1100));
+/// sem1.add_course(&CourseID::new("TEST", 1200));
+///
+/// let mut sem2 = Semester::new(SemTime::Fall(2019));
+/// sem2.add_course(&CourseID::new("TEST", 1200));
+/// sem2.add_course(&CourseID::new("TEST", 1100));
+///
+/// let mut sched1 = Schedule::new();
+/// sched1.add_semester(sem1);
+///
+/// let mut sched2 = Schedule::new();
+/// sched2.add_semester(sem2);
+///
+/// let mut schedules = HashSet::new();
+/// schedules.insert(sched1);
+/// schedules.insert(sched2);
+///
+/// assert_eq!(schedules.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct Schedule {
+    semesters: BTreeMap<SemTime, Semester>,
+}
+
+impl Schedule {
+    /// Generates a new schedule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::Schedule;
+    /// #
+    /// let schedule = Schedule::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            semesters: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a semester to the schedule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Schedule, Semester, SemTime};
+    /// #
+    /// let mut schedule = Schedule::new();
+    /// let semester = Semester::new(SemTime::Fall(2019));
+    ///
+    /// schedule.add_semester(semester.clone());
+    /// ```
+    pub fn add_semester(&mut self, sem: Semester) {
+        self.semesters.insert(sem.time.clone(), sem);
+    }
+
+    /// Adds a course to the schedule at a given time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let mut schedule = Schedule::new();
+    /// let semester = Semester::new(SemTime::Fall(2019));
+    ///
+    /// schedule.add_semester(semester);
+    ///
+    /// assert!(schedule.add_course(&SemTime::Fall(2019), &CourseID::new("TEST", 1100)));
+    /// ```
+    pub fn add_course(&mut self, sem: &SemTime, coid: &CourseID) -> bool {
+        match self.get_semester_mut(sem) {
+            Some(sem) => {
+                sem.add_course(coid);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Adds each course to its paired semester.  Semesters missing from the
+    /// schedule are not created; the courses which could not be placed
+    /// because of this are returned in the order given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new(SemTime::Fall(2019)));
+    ///
+    /// let placed = CourseID::new("TEST", 1100);
+    /// let unplaced = CourseID::new("TEST", 1200);
+    ///
+    /// let failed = schedule.overlay(&[
+    ///     (placed.clone(), SemTime::Fall(2019)),
+    ///     (unplaced.clone(), SemTime::Spring(2020)),
+    /// ]);
+    ///
+    /// assert_eq!(failed, vec![unplaced]);
+    /// assert_eq!(schedule.get_time(&placed), Some(&SemTime::Fall(2019)));
+    /// assert!(schedule.get_semester(&SemTime::Spring(2020)).is_none());
+    /// ```
+    pub fn overlay(&mut self, placements: &[(CourseID, SemTime)]) -> Vec<CourseID> {
+        placements
+            .iter()
+            .filter(|(coid, time)| !self.add_course(time, coid))
+            .map(|(coid, _)| coid.clone())
+            .collect()
+    }
+
+    /// Returns a reference to the semester corresponding to the given
+    /// SemTime, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Schedule, Semester, SemTime};
+    /// #
+    /// let mut schedule = Schedule::new();
+    /// let semester = Semester::new(SemTime::Fall(2019));
+    ///
+    /// schedule.add_semester(semester);
+    ///
+    /// assert!(schedule.get_semester(&SemTime::Fall(2019)).is_some());
+    /// ```
+    pub fn get_semester(&self, sem: &SemTime) -> Option<&Semester> {
+        self.semesters.get(sem)
+    }
+
+    /// Returns a mutable reference to the semester corresponding to the
+    /// given SemTime, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Schedule, Semester, SemTime};
+    /// #
+    /// let mut schedule = Schedule::new();
+    /// let semester = Semester::new(SemTime::Fall(2019));
+    ///
+    /// schedule.add_semester(semester);
+    ///
+    /// assert!(schedule.get_semester_mut(&SemTime::Fall(2019)).is_some());
+    /// ```
+    pub fn get_semester_mut(&mut self, sem: &SemTime) -> Option<&mut Semester> {
+        self.semesters.get_mut(sem)
+    }
+
+    /// Removes a semester from the schedule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Schedule, Semester, SemTime};
+    /// #
+    /// let mut schedule = Schedule::new();
+    /// let semester = Semester::new(SemTime::Fall(2019));
+    ///
+    /// schedule.add_semester(semester);
+    ///
+    /// assert!(schedule.get_semester(&SemTime::Fall(2019)).is_some());
+    ///
+    /// schedule.remove_semester(&SemTime::Fall(2019));
+    ///
+    /// assert!(schedule.get_semester(&SemTime::Fall(2019)).is_none());
+    /// ```
+    pub fn remove_semester(&mut self, sem: &SemTime) {
+        self.semesters.remove(sem);
+    }
+
+    /// Fills a slot in the given semester with a concrete course, as long as
+    /// the course can be placed there.  The schedule is left unchanged if
+    /// the semester has no such slot or the placement is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, GenerationConfig, PlacementIssue, Schedule, Semester, SemTime};
+    /// #
+    /// let elective = CourseID::new("PHIL", 1100);
+    /// let mut catalog = Catalog::new();
+    /// catalog.emplace_course(&elective);
+    ///
+    /// let mut fall = Semester::new(SemTime::Fall(2019));
+    /// fall.add_slot("HASS Elective");
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(fall);
+    ///
+    /// let config = GenerationConfig::default();
+    /// let fill = |schedule: &mut Schedule| {
+    ///     schedule.fill_slot(&SemTime::Fall(2019), "HASS Elective", &elective, &catalog, &config)
+    /// };
+    ///
+    /// assert_eq!(fill(&mut schedule), Ok(()));
+    /// assert_eq!(schedule.get_time(&elective), Some(&SemTime::Fall(2019)));
+    /// assert!(matches!(fill(&mut schedule), Err(PlacementIssue::NoSuchSlot { .. })));
+    /// ```
+    pub fn fill_slot(
+        &mut self,
+        sem: &SemTime,
+        label: &str,
+        coid: &CourseID,
+        catalog: &Catalog,
+        config: &GenerationConfig,
+    ) -> Result<(), PlacementIssue> {
+        let has_slot = self
+            .get_semester(sem)
+            .is_some_and(|semester| semester.slots.iter().any(|slot| slot == label));
+        if !has_slot {
+            return Err(PlacementIssue::NoSuchSlot {
+                time: sem.clone(),
+                label: String::from(label),
+            });
+        }
+
+        if let Some(time) = self.get_time(coid) {
+            if !is_repeatable(coid, catalog) {
+                return Err(PlacementIssue::AlreadyInSemester {
+                    course: coid.clone(),
+                    time: time.clone(),
+                });
+            }
+        }
+
+        if let Some(issue) = self.placement_issue(coid, sem, catalog, config, Some(label)) {
+            return Err(issue);
+        }
+
+        self.semesters.get_mut(sem).unwrap().fill_slot(label, coid);
+        Ok(())
+    }
+
+    /// Moves the semester at `from` to `to`, keeping all of its courses.
+    /// Returns false, leaving the schedule unchanged, if there is no
+    /// semester at `from` or there is already one at `to`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let coid = CourseID::new("TEST", 1100);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![coid.clone()]));
+    /// schedule.add_semester(Semester::new(SemTime::Spring(2020)));
+    ///
+    /// assert!(schedule.retime_semester(&SemTime::Fall(2019), &SemTime::Fall(2020)));
+    /// assert!(schedule.get_semester(&SemTime::Fall(2019)).is_none());
+    /// assert_eq!(schedule.get_time(&coid), Some(&SemTime::Fall(2020)));
+    /// assert_eq!(
+    ///     schedule.get_semester(&SemTime::Fall(2020)).unwrap().get_time(),
+    ///     &SemTime::Fall(2020)
+    /// );
+    ///
+    /// assert!(!schedule.retime_semester(&SemTime::Fall(2019), &SemTime::Fall(2021)));
+    /// assert!(!schedule.retime_semester(&SemTime::Fall(2020), &SemTime::Spring(2020)));
+    /// ```
+    pub fn retime_semester(&mut self, from: &SemTime, to: &SemTime) -> bool {
+        if self.semesters.contains_key(to) {
+            return false;
+        }
+
+        match self.semesters.remove(from) {
+            Some(mut semester) => {
+                semester.time = to.clone();
+                self.semesters.insert(to.clone(), semester);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns a reference to the semesters in the schedule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Schedule, Semester, SemTime};
+    /// #
+    /// let mut schedule = Schedule::new();
+    /// let sem1 = Semester::new(SemTime::Fall(2019));
+    /// let sem2 = Semester::new(SemTime::Spring(2020));
+    ///
+    /// schedule.add_semester(sem1);
+    /// schedule.add_semester(sem2);
+    ///
+    /// schedule.semesters();
+    /// ```
+    pub fn semesters(&self) -> &BTreeMap<SemTime, Semester> {
+        &self.semesters
+    }
+
+    /// Adds an empty semester directly after the latest semester in the
+    /// schedule, skipping summer semesters unless `include_summer` is set.
+    /// Returns the time of the new semester, or `None` if the schedule has
+    /// no semesters to follow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Schedule, Semester, SemTime};
+    /// #
+    /// let mut schedule = Schedule::new();
+    /// assert_eq!(schedule.append_next_term(false), None);
+    ///
+    /// schedule.add_semester(Semester::new(SemTime::Fall(2023)));
+    ///
+    /// assert_eq!(schedule.append_next_term(false), Some(SemTime::Spring(2024)));
+    /// assert_eq!(schedule.append_next_term(false), Some(SemTime::Fall(2024)));
+    /// assert!(schedule.get_semester(&SemTime::Fall(2024)).is_some());
+    /// ```
+    pub fn append_next_term(&mut self, include_summer: bool) -> Option<SemTime> {
+        let mut time = self.semesters.keys().next_back()?.next();
+        if let SemTime::Summer(_) = time {
+            if !include_summer {
+                time = time.next();
+            }
+        }
+
+        self.add_semester(Semester::new(time.clone()));
+        Some(time)
+    }
+
+    /// Moves every semester in the schedule `delta` years later (or
+    /// earlier, if `delta` is negative), keeping each semester's season.
+    /// This rebases a plan built with placeholder years onto real ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let coid = CourseID::new("TEST", 1100);
+    ///
+    /// let mut fall = Semester::new(SemTime::Fall(0));
+    /// fall.add_course(&coid);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(fall);
+    /// schedule.add_semester(Semester::new(SemTime::Spring(1)));
+    ///
+    /// schedule.shift_years(2024);
+    ///
+    /// assert_eq!(
+    ///     schedule.semester_times(),
+    ///     vec![SemTime::Fall(2024), SemTime::Spring(2025)]
+    /// );
+    /// assert_eq!(schedule.get_time(&coid), Some(&SemTime::Fall(2024)));
+    /// ```
+    pub fn shift_years(&mut self, delta: i32) {
+        let semesters = std::mem::take(&mut self.semesters);
+        for (_, mut semester) in semesters {
+            semester.time = semester.time.shift_years(delta);
+            self.add_semester(semester);
+        }
+    }
+
+    /// Returns the schedule as a flat JSON array with one
+    /// `{"term": "Fall 2019", "courses": ["CSCI 1100", ...]}` object per
+    /// semester, in chronological order.  Only the courses in each semester
+    /// are kept; statuses and grades are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// # use serde_json::json;
+    /// #
+    /// let mut fall = Semester::new(SemTime::Fall(2019));
+    /// fall.add_course(&CourseID::new("CSCI", 1200));
+    /// fall.add_course(&CourseID::new("CSCI", 1100));
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(fall);
+    /// schedule.add_semester(Semester::new(SemTime::Spring(2020)));
+    ///
+    /// let flat = schedule.to_flat_json();
+    /// assert_eq!(
+    ///     flat,
+    ///     json!([
+    ///         { "term": "Fall 2019", "courses": ["CSCI 1100", "CSCI 1200"] },
+    ///         { "term": "Spring 2020", "courses": [] }
+    ///     ])
+    /// );
+    ///
+    /// assert_eq!(Schedule::from_flat_json(&flat).unwrap(), schedule);
+    /// ```
+    pub fn to_flat_json(&self) -> serde_json::Value {
+        let terms = self
+            .semesters
+            .iter()
+            .map(|(time, semester)| {
+                let courses: Vec<String> = semester
+                    .courses
+                    .keys()
+                    .map(|coid| coid.to_string())
+                    .collect();
+                serde_json::json!({
+                    "term": time.to_string(),
+                    "courses": courses,
+                })
+            })
+            .collect();
+
+        serde_json::Value::Array(terms)
+    }
+
+    /// Reads a schedule from the flat form produced by `to_flat_json`.
+    /// Every course is `Planned`.
+    pub fn from_flat_json(value: &serde_json::Value) -> Result<Schedule, serde_json::Error> {
+        #[derive(Deserialize)]
+        struct FlatTerm {
+            term: String,
+            courses: Vec<CourseID>,
+        }
+
+        let terms = Vec::<FlatTerm>::deserialize(value)?;
+
+        let mut schedule = Schedule::new();
+        for term in terms {
+            let time = term
+                .term
+                .parse::<SemTime>()
+                .map_err(serde::de::Error::custom)?;
+
+            let mut semester = Semester::new(time);
+            for coid in &term.courses {
+                semester.add_course(coid);
+            }
+            schedule.add_semester(semester);
+        }
+
+        Ok(schedule)
+    }
+
+    /// Returns the times of every semester in the schedule in chronological
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Schedule, Semester, SemTime};
+    /// #
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new(SemTime::Fall(2020)));
+    /// schedule.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// schedule.add_semester(Semester::new(SemTime::Spring(2020)));
+    ///
+    /// assert_eq!(
+    ///     schedule.semester_times(),
+    ///     vec![SemTime::Fall(2019), SemTime::Spring(2020), SemTime::Fall(2020)]
+    /// );
+    /// ```
+    pub fn semester_times(&self) -> Vec<SemTime> {
+        self.semesters.keys().cloned().collect()
+    }
+
+    /// Returns if the given course can be found in the schedule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let coid = CourseID::new("TEST", 1100);
+    /// let coid2 = CourseID::new("TEST", 1200);
+    ///
+    /// let mut semester = Semester::new(SemTime::Fall(2019));
+    /// semester.add_course(&coid);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(semester);
+    ///
+    /// assert!(schedule.contains(&coid));
+    /// assert!(!schedule.contains(&coid2));
+    /// ```
+    pub fn contains(&self, coid: &CourseID) -> bool {
+        self.get_time(coid).is_some()
+    }
+
+    /// Returns if the schedule contains the course or a course the catalog
+    /// considers equivalent to it, such as transfer credit.  See
+    /// `Catalog::add_equivalency`.
+    pub fn contains_equivalent(&self, coid: &CourseID, catalog: &Catalog) -> bool {
+        self.semesters
+            .values()
+            .any(|semester| semester.contains_equivalent(coid, catalog))
+    }
+
+    /// Returns every course marked `Completed` in any semester of the
+    /// schedule.  This can be used as the `completed` set of a
+    /// `GenerationConfig`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, CourseStatus, Schedule, Semester, SemTime};
+    /// #
+    /// let completed = CourseID::new("TEST", 1100);
+    /// let planned = CourseID::new("TEST", 1200);
+    ///
+    /// let mut fall = Semester::new(SemTime::Fall(2019));
+    /// fall.set_status(&completed, CourseStatus::Completed);
+    ///
+    /// let mut spring = Semester::new(SemTime::Spring(2020));
+    /// spring.add_course(&planned);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(fall);
+    /// schedule.add_semester(spring);
+    ///
+    /// let courses = schedule.completed_courses();
+    ///
+    /// assert_eq!(courses.len(), 1);
+    /// assert!(courses.contains(&completed));
+    /// ```
+    pub fn completed_courses(&self) -> HashSet<CourseID> {
+        self.semesters
+            .values()
+            .flat_map(|semester| semester.courses.iter())
+            .filter(|(_, status)| **status == CourseStatus::Completed)
+            .map(|(coid, _)| coid.clone())
+            .collect()
+    }
+
+    /// Returns the first semester the given course can be found in within
+    /// the schedule, if such a course exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let coid = CourseID::new("TEST", 1100);
+    /// let coid2 = CourseID::new("TEST", 1200);
+    ///
+    /// let mut semester = Semester::new(SemTime::Fall(2019));
+    /// semester.add_course(&coid);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(semester);
+    ///
+    /// assert_eq!(schedule.get_time(&coid), Some(&SemTime::Fall(2019)));
+    /// assert!(schedule.get_time(&coid2).is_none());
+    /// ```
+    pub fn get_time(&self, coid: &CourseID) -> Option<&SemTime> {
+        for (time, semester) in &self.semesters {
+            if semester.contains(coid) {
+                return Some(time);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the semester each of the given courses is scheduled in,
+    /// leaving out courses which are not scheduled.  Courses scheduled more
+    /// than once map to their earliest semester, as with `get_time`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let data = CourseID::new("CSCI", 1200);
+    /// let algo = CourseID::new("CSCI", 2300);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![intro.clone()]));
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Spring(2020), vec![data.clone()]));
+    ///
+    /// let terms = schedule.terms_of(&[intro.clone(), data.clone(), algo.clone()]);
+    ///
+    /// assert_eq!(terms.len(), 2);
+    /// assert_eq!(terms[&intro], &SemTime::Fall(2019));
+    /// assert_eq!(terms[&data], &SemTime::Spring(2020));
+    /// assert!(!terms.contains_key(&algo));
+    /// ```
+    pub fn terms_of<'a>(&'a self, coids: &[CourseID]) -> HashMap<CourseID, &'a SemTime> {
+        let mut terms = HashMap::new();
+
+        for (time, semester) in &self.semesters {
+            for coid in coids {
+                if semester.contains(coid) {
+                    terms.entry(coid.clone()).or_insert(time);
+                }
+            }
+        }
+
+        terms
+    }
+
+    /// Returns a copy of the schedule with the given course removed from
+    /// every semester it appears in.  The original schedule is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let coid = CourseID::new("TEST", 1100);
+    ///
+    /// let mut semester = Semester::new(SemTime::Fall(2019));
+    /// semester.add_course(&coid);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(semester);
+    ///
+    /// let dropped = schedule.without_course(&coid);
+    ///
+    /// assert!(schedule.contains(&coid));
+    /// assert!(!dropped.contains(&coid));
+    /// assert!(dropped.get_semester(&SemTime::Fall(2019)).is_some());
+    /// ```
+    pub fn without_course(&self, coid: &CourseID) -> Schedule {
+        let mut new_sched = self.clone();
+        for semester in new_sched.semesters.values_mut() {
+            semester.remove_course(coid);
+        }
+        new_sched
+    }
+
+    /// Exchanges the semesters of two scheduled courses, keeping each
+    /// course's status and grade.  Returns false without changing anything
+    /// if either course is not scheduled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let calc = CourseID::new("MATH", 1010);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![intro.clone()]));
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Spring(2020), vec![calc.clone()]));
+    ///
+    /// assert!(schedule.swap_courses(&intro, &calc));
+    /// assert_eq!(schedule.get_time(&intro), Some(&SemTime::Spring(2020)));
+    /// assert_eq!(schedule.get_time(&calc), Some(&SemTime::Fall(2019)));
+    ///
+    /// assert!(!schedule.swap_courses(&intro, &CourseID::new("PHYS", 1100)));
+    /// assert_eq!(schedule.get_time(&intro), Some(&SemTime::Spring(2020)));
+    /// ```
+    pub fn swap_courses(&mut self, a: &CourseID, b: &CourseID) -> bool {
+        let (a_time, b_time) = match (self.get_time(a), self.get_time(b)) {
+            (Some(a_time), Some(b_time)) => (a_time.clone(), b_time.clone()),
+            _ => return false,
+        };
+
+        if a_time == b_time {
+            return true;
+        }
+
+        let a_sem = self.semesters.get_mut(&a_time).unwrap();
+        let a_status = a_sem.courses.remove(a).unwrap();
+        let a_grade = a_sem.grades.remove(a);
+
+        let b_sem = self.semesters.get_mut(&b_time).unwrap();
+        let b_status = b_sem.courses.remove(b).unwrap();
+        let b_grade = b_sem.grades.remove(b);
+        b_sem.courses.insert(a.clone(), a_status);
+        if let Some(grade) = a_grade {
+            b_sem.grades.insert(a.clone(), grade);
+        }
+
+        let a_sem = self.semesters.get_mut(&a_time).unwrap();
+        a_sem.courses.insert(b.clone(), b_status);
+        if let Some(grade) = b_grade {
+            a_sem.grades.insert(b.clone(), grade);
+        }
+
+        true
+    }
+
+    /// Returns every course which is scheduled in more than one semester,
+    /// along with the semesters it appears in.  Courses are sorted by ID
+    /// and their semesters chronologically.  Courses the catalog marks as
+    /// repeatable are never reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let repeated = CourseID::new("TEST", 1100);
+    /// let single = CourseID::new("TEST", 1200);
+    /// let seminar = CourseID::new("TEST", 2990);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&seminar).repeatable(true).build());
+    ///
+    /// let mut fall = Semester::new(SemTime::Fall(2019));
+    /// fall.add_course(&repeated);
+    /// fall.add_course(&single);
+    ///
+    /// let mut spring = Semester::new(SemTime::Spring(2020));
+    /// spring.add_course(&repeated);
+    ///
+    /// fall.add_course(&seminar);
+    /// spring.add_course(&seminar);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(fall);
+    /// schedule.add_semester(spring);
+    ///
+    /// assert_eq!(
+    ///     schedule.duplicate_courses(&catalog),
+    ///     vec![(repeated.clone(), vec![SemTime::Fall(2019), SemTime::Spring(2020)])]
+    /// );
+    ///
+    /// schedule.dedup_courses(&catalog);
+    /// assert!(schedule.duplicate_courses(&catalog).is_empty());
+    /// assert_eq!(schedule.get_time(&repeated), Some(&SemTime::Fall(2019)));
+    /// assert!(schedule.get_semester(&SemTime::Spring(2020)).unwrap().contains(&seminar));
+    /// ```
+    pub fn duplicate_courses(&self, catalog: &Catalog) -> Vec<(CourseID, Vec<SemTime>)> {
+        let mut times: BTreeMap<&CourseID, Vec<SemTime>> = BTreeMap::new();
+        for (time, semester) in &self.semesters {
+            for coid in semester.courses.keys() {
+                times.entry(coid).or_default().push(time.clone());
+            }
+        }
+
+        times
+            .into_iter()
+            .filter(|(coid, times)| times.len() > 1 && !is_repeatable(coid, catalog))
+            .map(|(coid, times)| (coid.clone(), times))
+            .collect()
+    }
+
+    /// Removes every course which is scheduled in more than one semester
+    /// from all but the earliest semester it appears in.  Courses the
+    /// catalog marks as repeatable are left in every semester.
+    pub fn dedup_courses(&mut self, catalog: &Catalog) {
+        let mut seen = HashSet::new();
+        for semester in self.semesters.values_mut() {
+            semester.retain(|coid| is_repeatable(coid, catalog) || seen.insert(coid.clone()));
+        }
+    }
+
+    /// Explains why the given course cannot be added to the schedule at the
+    /// given time without adding any other courses, returning the first
+    /// problem found.  Returns `None` if the course can be placed there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, GenerationConfig, PlacementIssue, Schedule, Semester, SemTime};
+    /// #
+    /// let prereq = CourseID::new("TEST", 1100);
+    /// let coid = CourseID::new("TEST", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.emplace_course(&coid);
+    /// catalog.get_course_mut(&coid).unwrap().add_prereq(&prereq);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new(SemTime::Fall(2019)));
+    ///
+    /// let config = GenerationConfig::default();
+    /// let issue = schedule.explain_placement(&coid, &SemTime::Fall(2019), &catalog, &config);
+    ///
+    /// assert_eq!(
+    ///     issue,
+    ///     Some(PlacementIssue::PrereqNotMet {
+    ///         course: coid,
+    ///         options: vec![prereq],
+    ///     })
+    /// );
+    /// ```
+    pub fn explain_placement(
+        &self,
+        coid: &CourseID,
+        sem: &SemTime,
+        catalog: &Catalog,
+        config: &GenerationConfig,
+    ) -> Option<PlacementIssue> {
+        self.placement_issue(coid, sem, catalog, config, None)
+    }
+
+    /// Explains why the course cannot be placed, as with
+    /// `explain_placement`.  If `slot` is set the course fills that slot,
+    /// and otherwise it fills the slot generation would choose, if any.
+    fn placement_issue(
+        &self,
+        coid: &CourseID,
+        sem: &SemTime,
+        catalog: &Catalog,
+        config: &GenerationConfig,
+        slot: Option<&str>,
+    ) -> Option<PlacementIssue> {
+        if self.contains(coid) && !is_repeatable(coid, catalog) {
+            return None;
+        }
+
+        let course = match catalog.get_course(coid) {
+            Some(course) => course,
+            None => return Some(PlacementIssue::NotInCatalog(coid.clone())),
+        };
+
+        if self.get_semester(sem).is_none() {
+            return Some(PlacementIssue::NoSuchSemester(sem.clone()));
+        }
+
+        if self.semesters[sem].contains(coid) {
+            return Some(PlacementIssue::AlreadyInSemester {
+                course: coid.clone(),
+                time: sem.clone(),
+            });
+        }
+
+        if let Some(pinned) = config.pinned.get(coid) {
+            if pinned != sem {
+                return Some(PlacementIssue::Pinned {
+                    course: coid.clone(),
+                    pinned: pinned.clone(),
+                });
+            }
+        }
+
+        let summer_forbidden =
+            config.summer == SummerPolicy::Forbid && sem.season() == Season::Summer;
+        if summer_forbidden || config.forbidden.contains(&(coid.clone(), sem.clone())) {
+            return Some(PlacementIssue::Forbidden {
+                course: coid.clone(),
+                time: sem.clone(),
+            });
+        }
+
+        let semester = &self.semesters[sem];
+        for (a, b) in &config.no_cooccur {
+            let other = if a == coid {
+                b
+            } else if b == coid {
+                a
+            } else {
+                continue;
+            };
+
+            if semester.contains(other) {
+                return Some(PlacementIssue::Conflict {
+                    course: coid.clone(),
+                    other: other.clone(),
+                });
+            }
+        }
+
+        // Unfilled slots take up room, which a course filling one replaces
+        let fills_slot = match slot {
+            Some(label) => semester.slots.iter().any(|slot| slot == label),
+            None => semester.slot_for(course).is_some(),
+        };
+        let slots = semester.slots.len() - usize::from(fills_slot);
+
+        let over_credit_cap = config.max_credits_per_semester.is_some_and(|cap| {
+            semester.total_credits(catalog).1
+                + slots as u32 * config.slot_credits
+                + u32::from(course.credits().max())
+                > cap
+        });
+        let over_course_cap = config
+            .max_courses_per_semester
+            .is_some_and(|cap| semester.courses.len() + slots >= cap);
+        if over_credit_cap || over_course_cap {
+            return Some(PlacementIssue::SemesterFull {
+                course: coid.clone(),
+                time: sem.clone(),
+            });
+        }
+
+        let over_term_cap = config.max_terms.is_some_and(|cap| {
+            semester.courses.is_empty()
+                && self
+                    .semesters
+                    .values()
+                    .filter(|semester| !semester.courses.is_empty())
+                    .count()
+                    >= cap
+        });
+        if over_term_cap {
+            return Some(PlacementIssue::TooManyTerms {
+                course: coid.clone(),
+                time: sem.clone(),
+            });
+        }
+
+        if let Some(other) = self.find_antireq(coid, course, catalog) {
+            return Some(PlacementIssue::Antirequisite {
+                course: coid.clone(),
+                other: other.clone(),
+            });
+        }
+
+        for coreq_set in course.coreq_sets() {
+            if coreq_set
+                .iter()
+                .any(|coreq| config.has_completed(coreq, catalog))
+            {
+                continue;
+            }
+
+            let mut contains_at_least_one = false;
+            let mut valid_time = false;
+            for coreq in coreq_set {
+                match self.get_time(coreq) {
+                    Some(time) => {
+                        contains_at_least_one = true;
+                        if time == sem {
+                            valid_time = true;
+                            break;
+                        }
+                    }
+                    None => continue,
+                };
+            }
+
+            if contains_at_least_one != valid_time {
+                return Some(PlacementIssue::CoreqNotConcurrent {
+                    course: coid.clone(),
+                    options: sorted(coreq_set),
+                });
+            }
+        }
+
+        for prereq_set in course.prereq_sets() {
+            if config.is_group_waived(coid, prereq_set) {
+                continue;
+            }
+
+            if !prereq_set.iter().any(|prereq| {
+                config.has_completed(prereq, catalog)
+                    || (prereq != coid
+                        && self
+                            .semesters
+                            .iter()
+                            .filter(|(time, _)| {
+                                *time < sem || (config.default_concurrent && *time == sem)
+                            })
+                            .any(|(_, semester)| semester.contains_equivalent(prereq, catalog)))
+            }) {
+                return Some(PlacementIssue::PrereqNotMet {
+                    course: coid.clone(),
+                    options: sorted(prereq_set),
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Returns a scheduled course which may not be taken alongside the given
+    /// course, whichever of the two lists the other as an antirequisite.
+    fn find_antireq<'a>(
+        &'a self,
+        coid: &CourseID,
+        course: &'a Course,
+        catalog: &Catalog,
+    ) -> Option<&'a CourseID> {
+        let mut antireqs: Vec<&CourseID> = course.antireqs().iter().collect();
+        antireqs.sort();
+        if let Some(other) = antireqs
+            .into_iter()
+            .find(|other| *other != coid && self.contains(other))
+        {
+            return Some(other);
+        }
+
+        self.semesters
+            .values()
+            .flat_map(|semester| semester.courses.keys())
+            .filter(|other| *other != coid)
+            .find(|other| {
+                catalog
+                    .get_course(other)
+                    .is_some_and(|other| other.antireqs().contains(coid))
+            })
+    }
+
+    /// Checks every course in the schedule against the catalog's
+    /// prerequisites, returning the problems found:
+    ///
+    /// - `PrereqNotMet` for each prerequisite group with no course in an
+    ///   earlier semester, whether that course is planned, in progress, or
+    ///   completed.
+    /// - `GradeNotMet` for every prerequisite which was passed in an earlier
+    ///   semester with too low a grade, when no other course in its
+    ///   prerequisite group was taken with a high enough grade.
+    ///   Prerequisites without a recorded grade are not checked.
+    /// - `Antirequisite` for every antirequisite of a course which is also
+    ///   scheduled, in any semester.
+    ///
+    /// Courses in `allow_concurrent` may also have their prerequisites
+    /// satisfied by a course in the same semester.  This models programs
+    /// which allow a sequence's courses to be taken together, treating those
+    /// prerequisites like corequisites.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, Course, CourseID, Grade, PlacementIssue, Schedule, Semester, SemTime};
+    /// # use serde_json::json;
+    /// # use std::collections::HashSet;
+    /// #
+    /// let intro = CourseID::new("CS", 101);
+    /// let data = CourseID::new("CS", 201);
+    ///
+    /// let course: Course = serde_json::from_value(json!({
+    /// # "complete": true,
+    /// # "name": "",
+    /// # "description": "",
+    /// # "offered": "",
+    /// # "age_reqs": "",
+    /// # "prereqs_opt": [],
+    /// # "coreqs": [],
+    /// # "coreqs_opt": [],
+    /// # "post_options": [],
+    ///     "coid": "CS 201",
+    ///     "prereqs": [[{ "course": "CS 101", "min_grade": "C" }]]
+    /// })).unwrap();
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.emplace_course(&intro);
+    /// catalog.add_course(course);
+    ///
+    /// let mut fall = Semester::new(SemTime::Fall(2019));
+    /// fall.set_grade(&intro, Grade::D);
+    /// let mut spring = Semester::new(SemTime::Spring(2020));
+    /// spring.add_course(&data);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(fall);
+    /// schedule.add_semester(spring);
+    ///
+    /// assert_eq!(
+    ///     schedule.validate(&catalog, &HashSet::new()),
+    ///     vec![PlacementIssue::GradeNotMet {
+    ///         course: data.clone(),
+    ///         prereq: intro.clone(),
+    ///         required: Grade::C,
+    ///         earned: Grade::D,
+    ///     }]
+    /// );
+    ///
+    /// schedule.get_semester_mut(&SemTime::Fall(2019)).unwrap().set_grade(&intro, Grade::C);
+    /// assert!(schedule.validate(&catalog, &HashSet::new()).is_empty());
+    ///
+    /// // Taking both courses in the same semester is only valid if allowed
+    /// let mut together = Semester::new(SemTime::Fall(2019));
+    /// together.add_course(&intro);
+    /// together.add_course(&data);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(together);
+    ///
+    /// assert_eq!(
+    ///     schedule.validate(&catalog, &HashSet::new()),
+    ///     vec![PlacementIssue::PrereqNotMet {
+    ///         course: data.clone(),
+    ///         options: vec![intro.clone()],
+    ///     }]
+    /// );
+    ///
+    /// let allow_concurrent: HashSet<CourseID> = vec![data.clone()].into_iter().collect();
+    /// assert!(schedule.validate(&catalog, &allow_concurrent).is_empty());
+    /// ```
+    ///
+    /// A low grade in one course of a prerequisite group is not reported when
+    /// another course in the group was passed with a high enough grade:
+    ///
+    /// ```
+    /// # use myca::{Catalog, Course, CourseID, Grade, PlacementIssue, Schedule, Semester, SemTime};
+    /// # use serde_json::json;
+    /// # use std::collections::HashSet;
+    /// #
+    /// let intro = CourseID::new("CS", 101);
+    /// let honors = CourseID::new("CS", 102);
+    /// let data = CourseID::new("CS", 201);
+    ///
+    /// let course: Course = serde_json::from_value(json!({
+    /// # "complete": true,
+    /// # "name": "",
+    /// # "description": "",
+    /// # "offered": "",
+    /// # "age_reqs": "",
+    /// # "prereqs_opt": [],
+    /// # "coreqs": [],
+    /// # "coreqs_opt": [],
+    /// # "post_options": [],
+    ///     "coid": "CS 201",
+    ///     "prereqs": [[
+    ///         { "course": "CS 101", "min_grade": "C" },
+    ///         { "course": "CS 102", "min_grade": "C" }
+    ///     ]]
+    /// })).unwrap();
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.emplace_course(&intro);
+    /// catalog.emplace_course(&honors);
+    /// catalog.add_course(course);
+    ///
+    /// let mut fall = Semester::new(SemTime::Fall(2019));
+    /// fall.set_grade(&intro, Grade::D);
+    /// fall.set_grade(&honors, Grade::B);
+    /// let mut spring = Semester::new(SemTime::Spring(2020));
+    /// spring.add_course(&data);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(fall);
+    /// schedule.add_semester(spring);
+    ///
+    /// assert!(schedule.validate(&catalog, &HashSet::new()).is_empty());
+    ///
+    /// schedule.get_semester_mut(&SemTime::Fall(2019)).unwrap().set_grade(&honors, Grade::D);
+    /// assert_eq!(
+    ///     schedule.validate(&catalog, &HashSet::new()),
+    ///     vec![
+    ///         PlacementIssue::GradeNotMet {
+    ///             course: data.clone(),
+    ///             prereq: intro.clone(),
+    ///             required: Grade::C,
+    ///             earned: Grade::D,
+    ///         },
+    ///         PlacementIssue::GradeNotMet {
+    ///             course: data.clone(),
+    ///             prereq: honors.clone(),
+    ///             required: Grade::C,
+    ///             earned: Grade::D,
+    ///         },
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// Courses which may not both be taken for credit are reported even when
+    /// they are in different semesters:
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, GenerationConfig, PlacementIssue, Schedule, Semester, SemTime};
+    /// # use std::collections::HashSet;
+    /// #
+    /// let standard = CourseID::new("CHEM", 1100);
+    /// let honors = CourseID::new("CHEM", 1150);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&standard).build());
+    /// catalog.add_course(CourseBuilder::new(&honors).antireq(&standard).build());
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![standard.clone()]));
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Spring(2020), vec![honors.clone()]));
+    ///
+    /// assert_eq!(
+    ///     schedule.validate(&catalog, &HashSet::new()),
+    ///     vec![PlacementIssue::Antirequisite {
+    ///         course: honors.clone(),
+    ///         other: standard.clone(),
+    ///     }]
+    /// );
+    ///
+    /// // Generation will not place either course once the other is scheduled
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![honors]));
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2020)));
+    ///
+    /// let config = GenerationConfig::default();
+    /// assert!(Schedule::add_course_to_schedule_with_config(&standard, &skeleton, &catalog, &config).is_empty());
+    /// ```
+    pub fn validate(
+        &self,
+        catalog: &Catalog,
+        allow_concurrent: &HashSet<CourseID>,
+    ) -> Vec<PlacementIssue> {
+        let mut issues = Vec::new();
+
+        for (time, semester) in &self.semesters {
+            for coid in semester.courses.keys() {
+                let course = match catalog.get_course(coid) {
+                    Some(course) => course,
+                    None => continue,
+                };
+
+                let concurrent = allow_concurrent.contains(coid);
+                for prereq_set in course.prereq_sets() {
+                    let satisfied = prereq_set.iter().any(|prereq| {
+                        self.semesters
+                            .range(..=time.clone())
+                            .any(|(prereq_time, semester)| {
+                                (prereq_time < time || concurrent)
+                                    && semester.contains_equivalent(prereq, catalog)
+                            })
+                    });
+
+                    if !satisfied {
+                        issues.push(PlacementIssue::PrereqNotMet {
+                            course: coid.clone(),
+                            options: sorted(prereq_set),
+                        });
+                    }
+                }
+
+                let mut low_grades = Vec::new();
+                for prereq_set in course.prereq_sets() {
+                    let mut group_low_grades = Vec::new();
+                    let mut passed = false;
+
+                    for prereq in sorted(prereq_set) {
+                        let taken =
+                            self.semesters
+                                .range(..=time.clone())
+                                .any(|(prereq_time, semester)| {
+                                    (prereq_time < time || concurrent)
+                                        && semester.contains_equivalent(&prereq, catalog)
+                                });
+                        if !taken {
+                            continue;
+                        }
+
+                        let earned = self
+                            .semesters
+                            .range(..time.clone())
+                            .rev()
+                            .find_map(|(_, earlier)| earlier.grade(&prereq));
+
+                        match (course.min_grade(&prereq), earned) {
+                            (Some(required), Some(earned)) if earned < required => {
+                                group_low_grades.push((prereq, required, earned))
+                            }
+                            _ => passed = true,
+                        }
+                    }
+
+                    if !passed {
+                        low_grades.extend(group_low_grades);
+                    }
+                }
+                low_grades.sort();
+                low_grades.dedup();
+
+                for (prereq, required, earned) in low_grades {
+                    issues.push(PlacementIssue::GradeNotMet {
+                        course: coid.clone(),
+                        prereq,
+                        required,
+                        earned,
+                    });
+                }
+
+                let mut antireqs: Vec<&CourseID> = course.antireqs().iter().collect();
+                antireqs.sort();
+
+                for other in antireqs {
+                    if other != coid && self.contains(other) {
+                        issues.push(PlacementIssue::Antirequisite {
+                            course: coid.clone(),
+                            other: other.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+
+    /// Returns if every scheduled course has each of its prerequisite
+    /// groups satisfied by a course in a strictly earlier semester, or by a
+    /// course the catalog considers equivalent to one.  Corequisites,
+    /// offerings, and grades are not checked; use `validate` for a full
+    /// report.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let prereq = CourseID::new("TEST", 1100);
+    /// let coid = CourseID::new("TEST", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.emplace_course(&prereq);
+    /// catalog.emplace_course(&coid);
+    /// catalog.get_course_mut(&coid).unwrap().add_prereq(&prereq);
+    ///
+    /// let mut in_order = Schedule::new();
+    /// in_order.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// in_order.add_semester(Semester::new(SemTime::Spring(2020)));
+    /// in_order.add_course(&SemTime::Fall(2019), &prereq);
+    /// in_order.add_course(&SemTime::Spring(2020), &coid);
+    ///
+    /// let mut out_of_order = Schedule::new();
+    /// out_of_order.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// out_of_order.add_semester(Semester::new(SemTime::Spring(2020)));
+    /// out_of_order.add_course(&SemTime::Fall(2019), &coid);
+    /// out_of_order.add_course(&SemTime::Spring(2020), &prereq);
+    ///
+    /// assert!(in_order.is_valid_order(&catalog));
+    /// assert!(!out_of_order.is_valid_order(&catalog));
+    ///
+    /// // Transfer credit for the prerequisite counts as well
+    /// let transfer = CourseID::new("XFER", 1000);
+    /// catalog.add_equivalency(transfer.clone(), prereq.clone());
+    ///
+    /// let mut transferred = Schedule::new();
+    /// transferred.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![transfer]));
+    /// transferred.add_semester(Semester::new_with_courses(SemTime::Spring(2020), vec![coid]));
+    ///
+    /// assert!(transferred.is_valid_order(&catalog));
+    /// ```
+    pub fn is_valid_order(&self, catalog: &Catalog) -> bool {
+        let mut taken: HashSet<&CourseID> = HashSet::new();
+
+        for semester in self.semesters.values() {
+            let satisfied = semester.courses.keys().all(|coid| {
+                catalog.get_course(coid).is_none_or(|course| {
+                    course.prereq_sets().iter().all(|set| {
+                        set.iter().any(|prereq| {
+                            taken.contains(prereq)
+                                || (catalog.has_equivalencies()
+                                    && taken.iter().any(|coid| catalog.is_equivalent(coid, prereq)))
+                        })
+                    })
+                })
+            });
+
+            if !satisfied {
+                return false;
+            }
+
+            taken.extend(semester.courses.keys());
+        }
+
+        true
+    }
+
+    fn try_add(
+        &self,
+        coid: &CourseID,
+        sem: &SemTime,
+        catalog: &Catalog,
+        config: &GenerationConfig,
+    ) -> Option<Self> {
+        if self.contains(coid) && !is_repeatable(coid, catalog) {
+            return Some(self.clone());
+        }
+
+        if self.explain_placement(coid, sem, catalog, config).is_some() {
+            return None;
+        }
+
+        let mut new_sched = self.clone();
+        new_sched.place(coid, sem, catalog);
+        Some(new_sched)
+    }
+
+    /// Adds the course to the semester, filling the slot generation would
+    /// choose for it if the semester has one.
+    fn place(&mut self, coid: &CourseID, sem: &SemTime, catalog: &Catalog) {
+        let semester = match self.semesters.get_mut(sem) {
+            Some(semester) => semester,
+            None => return,
+        };
+
+        let slot = catalog
+            .get_course(coid)
+            .and_then(|course| semester.slot_for(course))
+            .map(String::from);
+        match slot {
+            Some(label) => {
+                semester.fill_slot(&label, coid);
+            }
+            None => semester.add_course(coid),
+        }
+    }
+
+    /// Generates all possible schedules which can be created by adding the
+    /// given course into the schedule.
+    ///
+    /// Recursively adds prerequisites and corequisites based on the catalog
+    /// entry.  A course listed as its own prerequisite can never be
+    /// satisfied, so no schedules are generated for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, Schedule, Semester, SemTime};
+    /// # use serde_json::json;
+    /// #
+    /// let coid = CourseID::new("TEST", 1100);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(serde_json::from_value(json!({
+    /// # "complete": true,
+    /// # "name": "",
+    /// # "description": "",
+    /// # "offered": "",
+    /// # "age_reqs": "",
+    /// # "prereqs_opt": [],
+    /// # "coreqs": [],
+    /// # "coreqs_opt": [],
+    /// # "post_options": [],
+    ///     "coid": { "subj": "TEST", "code": 1100 },
+    ///     "prereqs": [[{ "subj": "TEST", "code": 1100 }]]
+    /// })).unwrap());
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2019)));
+    ///
+    /// // The self-prerequisite was dropped when the course was added
+    /// let schedules = Schedule::add_course_to_schedule(&coid, &skeleton, &catalog);
+    /// assert_eq!(schedules.len(), 1);
+    ///
+    /// // Generation still terminates if one is introduced afterwards
+    /// catalog.get_course_mut(&coid).unwrap().add_prereq(&coid);
+    /// let schedules = Schedule::add_course_to_schedule(&coid, &skeleton, &catalog);
+    /// assert!(schedules.is_empty());
+    /// ```
+    pub fn add_course_to_schedule(
+        coid: &CourseID,
+        sched: &Schedule,
+        catalog: &Catalog,
+    ) -> Vec<Schedule> {
+        Self::add_course_to_schedule_with_config(coid, sched, catalog, &GenerationConfig::default())
+    }
+
+    /// Generates all possible schedules which can be created by adding the
+    /// given course into the schedule, following the options set in the
+    /// given configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let prereq = CourseID::new("TEST", 1100);
+    /// let coid = CourseID::new("TEST", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.emplace_course(&coid);
+    /// catalog.get_course_mut(&coid).unwrap().add_prereq(&prereq);
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2020)));
+    ///
+    /// let mut config = GenerationConfig::default();
+    /// config.completed.insert(prereq.clone());
+    ///
+    /// let schedules = Schedule::add_course_to_schedule_with_config(&coid, &skeleton, &catalog, &config);
+    ///
+    /// assert!(schedules
+    ///     .iter()
+    ///     .any(|schedule| schedule.get_time(&coid) == Some(&SemTime::Fall(2019))));
+    /// assert!(schedules.iter().all(|schedule| !schedule.contains(&prereq)));
+    /// ```
+    ///
+    /// Waived prerequisites do not need to be scheduled:
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let prereq = CourseID::new("TEST", 1100);
+    /// let coid = CourseID::new("TEST", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.emplace_course(&prereq);
+    /// catalog.emplace_course(&coid);
+    /// catalog.get_course_mut(&coid).unwrap().add_prereq(&prereq);
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2019)));
+    ///
+    /// let mut config = GenerationConfig::default();
+    /// assert!(Schedule::add_course_to_schedule_with_config(&coid, &skeleton, &catalog, &config)
+    ///     .is_empty());
+    ///
+    /// config.waived_prereqs.insert((coid.clone(), prereq.clone()));
+    /// let schedules = Schedule::add_course_to_schedule_with_config(&coid, &skeleton, &catalog, &config);
+    ///
+    /// assert_eq!(schedules.len(), 1);
+    /// assert_eq!(schedules[0].get_time(&coid), Some(&SemTime::Fall(2019)));
+    /// assert!(!schedules[0].contains(&prereq));
+    /// ```
+    ///
+    /// Courses are never placed into semesters they are forbidden from:
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let coid = CourseID::new("CS", 101);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.emplace_course(&coid);
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2024)));
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2025)));
+    ///
+    /// let mut config = GenerationConfig::default();
+    /// config.forbidden.insert((coid.clone(), SemTime::Fall(2024)));
+    ///
+    /// let schedules = Schedule::add_course_to_schedule_with_config(&coid, &skeleton, &catalog, &config);
+    ///
+    /// assert_eq!(schedules.len(), 1);
+    /// assert!(schedules
+    ///     .iter()
+    ///     .all(|schedule| schedule.get_time(&coid) != Some(&SemTime::Fall(2024))));
+    /// ```
+    ///
+    /// Courses in `no_cooccur` are never placed in the same semester:
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let cs = CourseID::new("CS", 101);
+    /// let math = CourseID::new("MATH", 101);
+    /// let goal = CourseID::new("CS", 201);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.emplace_course(&cs);
+    /// catalog.emplace_course(&math);
+    /// catalog.emplace_course(&goal);
+    /// catalog.get_course_mut(&goal).unwrap().add_prereq(&cs);
+    /// catalog.get_course_mut(&goal).unwrap().add_prereq(&math);
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2020)));
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2020)));
+    ///
+    /// let mut config = GenerationConfig::default();
+    /// config.no_cooccur.push((cs.clone(), math.clone()));
+    ///
+    /// let schedules = Schedule::add_course_to_schedule_with_config(&goal, &skeleton, &catalog, &config);
+    ///
+    /// assert!(!schedules.is_empty());
+    /// assert!(schedules
+    ///     .iter()
+    ///     .all(|schedule| schedule.get_time(&cs) != schedule.get_time(&math)));
+    /// ```
+    ///
+    /// With `default_concurrent` set, prerequisites may share a semester
+    /// with the courses which require them:
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let prereq = CourseID::new("TEST", 1100);
+    /// let coid = CourseID::new("TEST", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.emplace_course(&prereq);
+    /// catalog.emplace_course(&coid);
+    /// catalog.get_course_mut(&coid).unwrap().add_prereq(&prereq);
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2019)));
+    ///
+    /// let mut config = GenerationConfig::default();
+    /// assert!(Schedule::add_course_to_schedule_with_config(&coid, &skeleton, &catalog, &config)
+    ///     .is_empty());
+    ///
+    /// config.default_concurrent = true;
+    /// let schedules = Schedule::add_course_to_schedule_with_config(&coid, &skeleton, &catalog, &config);
+    ///
+    /// assert_eq!(schedules.len(), 1);
+    /// assert_eq!(schedules[0].get_time(&prereq), Some(&SemTime::Fall(2019)));
+    /// assert_eq!(schedules[0].get_time(&coid), Some(&SemTime::Fall(2019)));
+    /// ```
+    ///
+    /// Schedules with a semester below `min_credits_per_semester` are
+    /// discarded:
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, CreditRange, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let first = CourseID::new("TEST", 1100);
+    /// let second = CourseID::new("TEST", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&first).credits(CreditRange::fixed(6)).build());
+    /// catalog.add_course(CourseBuilder::new(&second).credits(CreditRange::fixed(6)).build());
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![first]));
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2020)));
+    ///
+    /// let mut config = GenerationConfig::default();
+    /// assert_eq!(
+    ///     Schedule::add_course_to_schedule_with_config(&second, &skeleton, &catalog, &config).len(),
+    ///     2
+    /// );
+    ///
+    /// // Taking the second course in the spring leaves two 6-credit terms
+    /// config.min_credits_per_semester = Some(12);
+    /// let schedules = Schedule::add_course_to_schedule_with_config(&second, &skeleton, &catalog, &config);
+    ///
+    /// assert_eq!(schedules.len(), 1);
+    /// assert_eq!(schedules[0].get_time(&second), Some(&SemTime::Fall(2019)));
+    /// ```
+    ///
+    /// Generation does not recurse, so very long prerequisite chains cannot
+    /// overflow the stack:
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let mut catalog = Catalog::new();
+    /// for code in 1..=100 {
+    ///     let coid = CourseID::new("TEST", code);
+    ///     catalog.emplace_course(&coid);
+    ///     if code > 1 {
+    ///         let prereq = CourseID::new("TEST", code - 1);
+    ///         catalog.get_course_mut(&coid).unwrap().add_prereq(&prereq);
+    ///     }
+    /// }
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// for year in 2019..2021 {
+    ///     skeleton.add_semester(Semester::new(SemTime::Fall(year)));
+    ///     skeleton.add_semester(Semester::new(SemTime::Spring(year + 1)));
+    /// }
+    ///
+    /// let config = GenerationConfig::default();
+    /// let schedules = Schedule::add_course_to_schedule_with_config(
+    ///     &CourseID::new("TEST", 100),
+    ///     &skeleton,
+    ///     &catalog,
+    ///     &config,
+    /// );
+    ///
+    /// // A 100-course chain cannot fit into four semesters
+    /// assert!(schedules.is_empty());
+    /// ```
+    pub fn add_course_to_schedule_with_config(
+        coid: &CourseID,
+        sched: &Schedule,
+        catalog: &Catalog,
+        config: &GenerationConfig,
+    ) -> Vec<Schedule> {
+        Self::generate(coid, sched, catalog, config).schedules
+    }
+
+    /// Generates all possible schedules which can be created by adding the
+    /// given course into the schedule, as with
+    /// `add_course_to_schedule_with_config`, but also reports whether
+    /// generation was cut short by the configured `timeout`.
+    ///
+    /// The schedules are collected from `add_course_to_schedule_iter`, so a
+    /// timed out generation still returns every complete schedule found
+    /// before the deadline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// # use std::time::Duration;
+    /// #
+    /// let prereq = CourseID::new("TEST", 1100);
+    /// let coid = CourseID::new("TEST", 1200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.emplace_course(&prereq);
+    /// catalog.emplace_course(&coid);
+    /// catalog.get_course_mut(&coid).unwrap().add_prereq(&prereq);
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2020)));
+    ///
+    /// let mut config = GenerationConfig::default();
+    /// config.timeout = Some(Duration::from_secs(3600));
+    ///
+    /// let result = Schedule::generate(&coid, &skeleton, &catalog, &config);
+    /// assert!(!result.timed_out);
+    /// assert_eq!(result.schedules.len(), 1);
+    ///
+    /// // A deadline which has already passed stops generation before any
+    /// // schedule is finished
+    /// config.timeout = Some(Duration::ZERO);
+    ///
+    /// let result = Schedule::generate(&coid, &skeleton, &catalog, &config);
+    /// assert!(result.timed_out);
+    /// assert!(result.schedules.is_empty());
+    /// ```
+    pub fn generate(
+        coid: &CourseID,
+        sched: &Schedule,
+        catalog: &Catalog,
+        config: &GenerationConfig,
+    ) -> GenerationResult {
+        let mut generation = LazyGeneration::new(coid, sched, catalog, config);
+        let mut result = GenerationResult::default();
+        while let Some((schedule, unplaced)) = generation.next_plan() {
+            result.schedules.push(schedule);
+            result.unplaced.push(unplaced);
+        }
+        result.timed_out = generation.timed_out;
+
+        result
+    }
+
+    /// Lazily generates the schedules returned by
+    /// `add_course_to_schedule_with_config`, in the same order, yielding each
+    /// one as soon as it is found.  Only the schedules along the current
+    /// branch of the search are kept in memory, so callers wanting just the
+    /// first few schedules never pay for building the rest.
+    ///
+    /// If the configured `timeout` elapses, the iterator simply ends.  With `best_effort` set, the same partial
+    /// schedules are yielded, although the courses left out of them are
+    /// only reported by `Schedule::generate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// // A 30-course chain over 40 semesters has hundreds of millions of
+    /// // possible schedules, far too many to build up front
+    /// let mut catalog = Catalog::new();
+    /// for code in 0..30 {
+    ///     let coid = CourseID::new("TEST", code);
+    ///     catalog.emplace_course(&coid);
+    ///     if code > 0 {
+    ///         catalog.get_course_mut(&coid).unwrap().add_prereq(&CourseID::new("TEST", code - 1));
+    ///     }
+    /// }
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// for year in 2019..2059 {
+    ///     skeleton.add_semester(Semester::new(SemTime::Fall(year)));
+    /// }
+    ///
+    /// let config = GenerationConfig::default();
+    /// let goal = CourseID::new("TEST", 29);
+    /// let mut generation = Schedule::add_course_to_schedule_iter(&goal, &skeleton, &catalog, &config);
+    /// let first: Vec<Schedule> = generation.by_ref().take(5).collect();
+    ///
+    /// assert_eq!(first.len(), 5);
+    /// assert!(first.iter().all(|schedule| schedule.course_count() == 30));
+    ///
+    /// // Only the first branches of the search were explored
+    /// assert!(generation.placements() < 2_000);
+    /// ```
+    ///
+    /// Best effort generation yields the partial schedules:
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// # use std::collections::HashSet;
+    /// #
+    /// let intro = CourseID::new("TEST", 1100);
+    /// let seminar = CourseID::new("TEST", 1900);
+    /// let workshop = CourseID::new("TEST", 1910);
+    /// let lab = CourseID::new("TEST", 2500);
+    /// let capstone = CourseID::new("TEST", 4000);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// for coid in &[&intro, &seminar, &workshop, &lab] {
+    ///     catalog.add_course(CourseBuilder::new(coid).build());
+    /// }
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&capstone)
+    ///         .prereq_group(vec![intro.clone()])
+    ///         .prereq_group(vec![seminar.clone(), workshop.clone()])
+    ///         .prereq_group(vec![lab.clone()])
+    ///         .build(),
+    /// );
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2020)));
+    ///
+    /// // Neither the lab nor the seminar can be taken in any semester
+    /// let mut config = GenerationConfig::default();
+    /// config.best_effort = true;
+    /// for time in skeleton.semester_times() {
+    ///     config.forbidden.insert((lab.clone(), time.clone()));
+    ///     config.forbidden.insert((seminar.clone(), time));
+    /// }
+    ///
+    /// let lazy: HashSet<Schedule> =
+    ///     Schedule::add_course_to_schedule_iter(&capstone, &skeleton, &catalog, &config).collect();
+    /// let result = Schedule::generate(&capstone, &skeleton, &catalog, &config);
+    ///
+    /// assert!(!lazy.is_empty());
+    /// assert_eq!(lazy, result.schedules.into_iter().collect());
+    /// for unplaced in &result.unplaced {
+    ///     assert_eq!(unplaced, &vec![lab.clone(), capstone.clone()]);
+    /// }
+    /// assert!(lazy
+    ///     .iter()
+    ///     .all(|schedule| schedule.contains(&intro) && schedule.contains(&workshop)));
+    /// ```
+    pub fn add_course_to_schedule_iter<'a>(
+        coid: &'a CourseID,
+        sched: &Schedule,
+        catalog: &'a Catalog,
+        config: &'a GenerationConfig,
+    ) -> LazyGeneration<'a> {
+        LazyGeneration::new(coid, sched, catalog, config)
+    }
+
+    /// Generates every schedule which adds all of the goal courses, and any
+    /// missing requisites, into the skeleton schedule.
+    ///
+    /// The goals are added one at a time, starting with those whose longest
+    /// prerequisite chains take the most terms to complete, as measured by
+    /// `Catalog::min_terms_to_complete` from the first semester, so those
+    /// chains claim the earliest semesters.  A goal already placed as
+    /// another goal's prerequisite is not added again, and identical
+    /// schedules reached through different goals are merged.  A skeleton
+    /// without any semesters has nowhere to place the goals, so no schedules
+    /// are generated for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let shared = CourseID::new("TEST", 1100);
+    /// let first = CourseID::new("TEST", 2100);
+    /// let second = CourseID::new("TEST", 2200);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// for coid in &[&shared, &first, &second] {
+    ///     catalog.emplace_course(coid);
+    /// }
+    /// catalog.get_course_mut(&first).unwrap().add_prereq(&shared);
+    /// catalog.get_course_mut(&second).unwrap().add_prereq(&shared);
+    ///
+    /// let mut skeleton = Schedule::new();
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// skeleton.add_semester(Semester::new(SemTime::Spring(2020)));
+    /// skeleton.add_semester(Semester::new(SemTime::Fall(2020)));
+    ///
+    /// let schedules = Schedule::schedule_for_goals(
+    ///     &[first.clone(), second.clone()],
+    ///     &skeleton,
+    ///     &catalog,
+    ///     &GenerationConfig::default(),
+    /// );
+    ///
+    /// assert!(!schedules.is_empty());
+    /// for schedule in &schedules {
+    ///     let placements = schedule
+    ///         .semesters()
+    ///         .values()
+    ///         .filter(|semester| semester.contains(&shared))
+    ///         .count();
+    ///     assert_eq!(placements, 1);
+    ///     assert!(schedule.contains(&first) && schedule.contains(&second));
+    /// }
+    ///
+    /// let empty = Schedule::new();
+    /// let config = GenerationConfig::default();
+    /// assert!(Schedule::schedule_for_goals(&[first], &empty, &catalog, &config).is_empty());
+    /// ```
+    pub fn schedule_for_goals(
+        coids: &[CourseID],
+        skeleton: &Schedule,
+        catalog: &Catalog,
+        config: &GenerationConfig,
+    ) -> Vec<Schedule> {
+        let start = match skeleton.semesters.keys().next() {
+            Some(start) => start.clone(),
+            None => return Vec::new(),
+        };
+        let mut goals: Vec<(usize, &CourseID)> = coids
+            .iter()
+            .map(|coid| {
+                let depth = catalog
+                    .min_terms_to_complete(coid, start.clone())
+                    .unwrap_or(0);
+                (depth, coid)
+            })
+            .collect();
+        goals.sort_by(|(a_depth, a), (b_depth, b)| b_depth.cmp(a_depth).then(a.cmp(b)));
+        goals.dedup_by(|(_, a), (_, b)| a == b);
+
+        let mut schedules = vec![skeleton.clone()];
+        for (_, coid) in goals {
+            let mut seen = HashSet::new();
+            schedules = schedules
+                .iter()
+                .flat_map(|schedule| {
+                    // Goals may already be placed as another goal's prereq
+                    if schedule.contains(coid) {
+                        vec![schedule.clone()]
+                    } else {
+                        Self::add_course_to_schedule_with_config(coid, schedule, catalog, config)
+                    }
+                })
+                .filter(|schedule| seen.insert(schedule.clone()))
+                .collect();
+        }
+
+        schedules
+    }
+
+    /// Places a single course into the schedule at the earliest semester
+    /// it can be taken, adding any missing prerequisites and corequisites
+    /// to valid semesters as well.  Returns the semester the course was
+    /// placed in.
+    ///
+    /// The schedule is the first one `add_course_to_schedule_iter` finds, so
+    /// no other schedules are generated.  Each missing requisite is placed in
+    /// the earliest semester it fits, before the course itself, choosing the
+    /// first option in sorted order from each requisite group which can be
+    /// placed.
+    ///
+    /// If the course cannot be placed, `None` is returned and the schedule
+    /// is left unchanged.  If the course is already in the schedule, its
+    /// current semester is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let intro = CourseID::new("TEST", 1100);
+    /// let advanced = CourseID::new("TEST", 2100);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.emplace_course(&intro);
+    /// catalog.emplace_course(&advanced);
+    /// catalog.get_course_mut(&advanced).unwrap().add_prereq(&intro);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// schedule.add_semester(Semester::new(SemTime::Spring(2020)));
+    /// schedule.add_semester(Semester::new(SemTime::Fall(2020)));
+    ///
+    /// assert_eq!(schedule.fill_course(&advanced, &catalog), Some(SemTime::Spring(2020)));
+    /// assert_eq!(schedule.get_time(&intro), Some(&SemTime::Fall(2019)));
+    /// assert_eq!(schedule.get_time(&advanced), Some(&SemTime::Spring(2020)));
+    /// ```
+    ///
+    /// Long prerequisite chains are placed without building every schedule
+    /// they allow:
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let mut catalog = Catalog::new();
+    /// for code in 0..30 {
+    ///     let coid = CourseID::new("TEST", code);
+    ///     catalog.emplace_course(&coid);
+    ///     if code > 0 {
+    ///         catalog.get_course_mut(&coid).unwrap().add_prereq(&CourseID::new("TEST", code - 1));
+    ///     }
+    /// }
+    ///
+    /// let mut schedule = Schedule::new();
+    /// for year in 2019..2059 {
+    ///     schedule.add_semester(Semester::new(SemTime::Fall(year)));
+    /// }
+    ///
+    /// let goal = CourseID::new("TEST", 29);
+    /// assert_eq!(schedule.fill_course(&goal, &catalog), Some(SemTime::Fall(2048)));
+    /// assert_eq!(schedule.course_count(), 30);
+    /// ```
+    pub fn fill_course(&mut self, coid: &CourseID, catalog: &Catalog) -> Option<SemTime> {
+        if let Some(time) = self.get_time(coid) {
+            return Some(time.clone());
+        }
+
+        // Generation tries semesters in order, so the first schedule found
+        // places each course as early as the courses before it allow
+        let config = GenerationConfig::default();
+        let schedule = Self::add_course_to_schedule_iter(coid, self, catalog, &config).next()?;
+        let time = schedule.get_time(coid)?.clone();
+
+        *self = schedule;
+        Some(time)
+    }
+
+    /// Returns a copy of the schedule with every planned course moved into
+    /// the earliest semester it can be taken in, so the plan finishes in as
+    /// few terms as possible.  Courses are re-placed in their original
+    /// order, which keeps prerequisites ahead of the courses requiring them,
+    /// and placements follow the configuration, including its credit and
+    /// course caps.
+    ///
+    /// Courses which are in progress or completed are never moved, and a
+    /// course which cannot be placed anywhere stays in its original
+    /// semester.  Semesters emptied by packing are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, CreditRange, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// // A six course chain, plus two courses without prerequisites
+    /// let chain: Vec<CourseID> = (1..=6).map(|level| CourseID::new("CSCI", level * 1000)).collect();
+    /// let electives = vec![CourseID::new("ARTS", 1100), CourseID::new("ARTS", 1200)];
+    ///
+    /// let mut catalog = Catalog::new();
+    /// for (i, coid) in chain.iter().enumerate() {
+    ///     let mut course = CourseBuilder::new(coid).credits(CreditRange::fixed(4));
+    ///     if i > 0 {
+    ///         course = course.prereq_group(vec![chain[i - 1].clone()]);
+    ///     }
+    ///     catalog.add_course(course.build());
+    /// }
+    /// for coid in &electives {
+    ///     catalog.add_course(CourseBuilder::new(coid).credits(CreditRange::fixed(4)).build());
+    /// }
+    ///
+    /// // One course per term takes eight terms
+    /// let mut schedule = Schedule::new();
+    /// for (i, coid) in electives.iter().chain(chain.iter()).enumerate() {
+    ///     let year = 2019 + i as i32;
+    ///     schedule.add_semester(Semester::new_with_courses(SemTime::Fall(year), vec![coid.clone()]));
+    /// }
+    /// assert_eq!(schedule.cost(&catalog).terms, 8);
+    ///
+    /// let mut config = GenerationConfig::default();
+    /// config.max_credits_per_semester = Some(16);
+    ///
+    /// let packed = schedule.pack(&catalog, &config);
+    /// assert_eq!(packed.cost(&catalog).terms, 6);
+    /// assert_eq!(packed.course_count(), 8);
+    /// assert!(packed.is_valid_order(&catalog));
+    /// assert_eq!(packed.get_time(&electives[1]), Some(&SemTime::Fall(2019)));
+    /// ```
+    pub fn pack(&self, catalog: &Catalog, config: &GenerationConfig) -> Schedule {
+        let mut packed = self.clone();
+        for semester in packed.semesters.values_mut() {
+            semester
+                .courses
+                .retain(|_, status| *status != CourseStatus::Planned);
+        }
+
+        for (time, semester) in &self.semesters {
+            for (coid, status) in &semester.courses {
+                if *status != CourseStatus::Planned
+                    || (packed.contains(coid) && !is_repeatable(coid, catalog))
+                {
+                    continue;
+                }
+
+                let target = packed
+                    .semesters
+                    .keys()
+                    .find(|target| {
+                        packed
+                            .explain_placement(coid, target, catalog, config)
+                            .is_none()
+                    })
+                    .unwrap_or(time)
+                    .clone();
+                packed.place(coid, &target, catalog);
+            }
+        }
+
+        packed
+    }
+
+    /// Plans for failing a course: the failed course and every scheduled
+    /// course which directly or transitively requires it are removed, then
+    /// placed again, in their original order, into the earliest valid
+    /// semesters after the one the course was failed in.
+    ///
+    /// Returns `None` if the failed course is not scheduled or if the
+    /// removed courses cannot all fit into the remaining semesters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, GenerationConfig, Schedule, Semester, SemTime};
+    /// #
+    /// let intro = CourseID::new("CSCI", 1100);
+    /// let data = CourseID::new("CSCI", 1200);
+    /// let algo = CourseID::new("CSCI", 2300);
+    /// let calc = CourseID::new("MATH", 1010);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// for coid in &[&intro, &data, &algo, &calc] {
+    ///     catalog.emplace_course(coid);
+    /// }
+    /// catalog.get_course_mut(&data).unwrap().add_prereq(&intro);
+    /// catalog.get_course_mut(&algo).unwrap().add_prereq(&data);
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![intro.clone()]));
+    /// schedule.add_semester(Semester::new_with_courses(
+    ///     SemTime::Spring(2020),
+    ///     vec![data.clone(), calc.clone()],
+    /// ));
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Fall(2020), vec![algo.clone()]));
+    /// schedule.add_semester(Semester::new(SemTime::Spring(2021)));
+    ///
+    /// let config = GenerationConfig::default();
+    /// let replanned = schedule.replan_after_failure(&intro, &catalog, &config).unwrap();
+    ///
+    /// assert_eq!(replanned.get_time(&intro), Some(&SemTime::Spring(2020)));
+    /// assert_eq!(replanned.get_time(&data), Some(&SemTime::Fall(2020)));
+    /// assert_eq!(replanned.get_time(&algo), Some(&SemTime::Spring(2021)));
+    /// assert_eq!(replanned.get_time(&calc), Some(&SemTime::Spring(2020)));
+    ///
+    /// // Failing the last course leaves no semester to retake it in
+    /// assert!(replanned.replan_after_failure(&algo, &catalog, &config).is_none());
+    /// ```
+    pub fn replan_after_failure(
+        &self,
+        failed: &CourseID,
         catalog: &Catalog,
-    ) -> Vec<Schedule> {
-        let course = match catalog.get_course(coid) {
-            Some(c) => c,
-            None => return Vec::new(),
-        };
+        config: &GenerationConfig,
+    ) -> Option<Schedule> {
+        let failed_time = self.get_time(failed)?.clone();
 
-        // Place prerequisites into schedule first
-        let mut prereq_scheds = vec![sched.clone()];
-        for prereq_set in course.prereq_sets() {
-            // curr_set will hold the set of all schedules from one of these prerequisites
-            let mut curr_set = Vec::new();
-            for prereq in prereq_set {
-                for schedule in &prereq_scheds {
-                    if !schedule.contains(prereq) {
-                        let mut prereq_options =
-                            Self::add_course_to_schedule(prereq, &schedule, catalog);
-                        curr_set.append(&mut prereq_options);
-                    } else {
-                        curr_set.push(schedule.clone());
-                    }
+        // Semesters are visited in order, so every affected prerequisite of
+        // a course has been found before the course itself is checked
+        let mut affected = vec![failed.clone()];
+        for semester in self.semesters.values() {
+            for coid in semester.courses.keys() {
+                if affected.contains(coid) {
+                    continue;
+                }
+
+                let requires_affected = catalog.get_course(coid).is_some_and(|course| {
+                    course
+                        .prereq_sets()
+                        .iter()
+                        .chain(course.coreq_sets())
+                        .flatten()
+                        .any(|req| affected.contains(req))
+                });
+                if requires_affected {
+                    affected.push(coid.clone());
                 }
             }
-            prereq_scheds = curr_set;
         }
 
-        // Add this course to the schedule
-        let mut prereq_and_this_scheds = Vec::new();
-        for sched in prereq_scheds {
-            for (time, _) in sched.semesters() {
-                if let Some(new_sched) = sched.try_add(coid, time, catalog) {
-                    prereq_and_this_scheds.push(new_sched);
-                }
+        let mut replanned = self.clone();
+        for coid in &affected {
+            for semester in replanned.semesters.values_mut() {
+                semester.remove_course(coid);
             }
         }
 
-        // Add all corequisite courses to the schedule
-        let mut all_scheds = prereq_and_this_scheds;
-        for coreq_set in course.coreq_sets() {
-            // curr_set will hold the set of all schedules from one of these corequisites
-            let mut curr_set = Vec::new();
-            for coreq in coreq_set {
-                for schedule in &all_scheds {
-                    if !schedule.contains(coreq) {
-                        let mut coreq_options =
-                            Self::add_course_to_schedule(coreq, &schedule, catalog);
-                        curr_set.append(&mut coreq_options);
-                    } else {
-                        curr_set.push(schedule.clone());
+        let later: Vec<SemTime> = self
+            .semesters
+            .range(failed_time..)
+            .skip(1)
+            .map(|(time, _)| time.clone())
+            .collect();
+
+        for coid in &affected {
+            let time = later.iter().find(|time| {
+                replanned
+                    .explain_placement(coid, time, catalog, config)
+                    .is_none()
+            })?;
+            replanned.place(coid, time, catalog);
+        }
+
+        Some(replanned)
+    }
+
+    /// Returns the schedule with the lowest score according to the given
+    /// scorer, or `None` if there are no schedules.  Ties are broken in
+    /// favor of the earlier schedule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, FewestTerms, Schedule, Semester, SemTime};
+    /// #
+    /// let first = CourseID::new("TEST", 1100);
+    /// let second = CourseID::new("TEST", 1200);
+    ///
+    /// let mut fall = Semester::new(SemTime::Fall(2019));
+    /// fall.add_course(&first);
+    /// let mut spring = Semester::new(SemTime::Spring(2020));
+    /// spring.add_course(&second);
+    ///
+    /// let mut long = Schedule::new();
+    /// long.add_semester(fall.clone());
+    /// long.add_semester(spring);
+    ///
+    /// fall.add_course(&second);
+    /// let mut short = Schedule::new();
+    /// short.add_semester(fall);
+    ///
+    /// let schedules = vec![long, short.clone()];
+    /// let best = Schedule::best(&schedules, &FewestTerms, &Catalog::new());
+    ///
+    /// assert_eq!(best, Some(&short));
+    /// ```
+    pub fn best<'a, S: ScheduleScorer>(
+        schedules: &'a [Schedule],
+        scorer: &S,
+        catalog: &Catalog,
+    ) -> Option<&'a Schedule> {
+        schedules
+            .iter()
+            .map(|schedule| (scorer.score(schedule, catalog), schedule))
+            .fold(
+                None,
+                |best: Option<(f64, &Schedule)>, (score, schedule)| match best {
+                    Some((best_score, _)) if best_score <= score => best,
+                    _ => Some((score, schedule)),
+                },
+            )
+            .map(|(_, schedule)| schedule)
+    }
+
+    /// Returns the total number of courses across every semester.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// schedule.add_semester(Semester::new(SemTime::Spring(2020)));
+    /// schedule.add_course(&SemTime::Fall(2019), &CourseID::new("TEST", 1100));
+    /// schedule.add_course(&SemTime::Spring(2020), &CourseID::new("TEST", 1200));
+    ///
+    /// assert_eq!(schedule.course_count(), 2);
+    /// ```
+    pub fn course_count(&self) -> usize {
+        self.semesters
+            .values()
+            .map(|semester| semester.courses.len())
+            .sum()
+    }
+
+    /// Returns the minimum and maximum number of credits in each semester,
+    /// in chronological order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, CreditRange, Schedule, Semester, SemTime};
+    /// #
+    /// let coid = CourseID::new("TEST", 1100);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&coid).credits(CreditRange::fixed(4)).build());
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// schedule.add_semester(Semester::new(SemTime::Spring(2020)));
+    /// schedule.add_course(&SemTime::Spring(2020), &coid);
+    ///
+    /// assert_eq!(
+    ///     schedule.credits_per_semester(&catalog),
+    ///     vec![(SemTime::Fall(2019), (0, 0)), (SemTime::Spring(2020), (4, 4))]
+    /// );
+    /// ```
+    pub fn credits_per_semester(&self, catalog: &Catalog) -> Vec<(SemTime, (u32, u32))> {
+        self.semesters
+            .iter()
+            .map(|(time, semester)| (time.clone(), semester.total_credits(catalog)))
+            .collect()
+    }
+
+    /// Returns the first and last semesters which contain at least one
+    /// course, or `None` if no courses are scheduled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// schedule.add_semester(Semester::new(SemTime::Spring(2020)));
+    /// schedule.add_semester(Semester::new(SemTime::Fall(2020)));
+    ///
+    /// assert_eq!(schedule.span(), None);
+    ///
+    /// schedule.add_course(&SemTime::Spring(2020), &CourseID::new("TEST", 1100));
+    /// assert_eq!(schedule.span(), Some((SemTime::Spring(2020), SemTime::Spring(2020))));
+    /// ```
+    pub fn span(&self) -> Option<(SemTime, SemTime)> {
+        let mut used = self
+            .semesters
+            .iter()
+            .filter(|(_, semester)| !semester.courses.is_empty())
+            .map(|(time, _)| time);
+
+        let first = used.next()?;
+        let last = used.next_back().unwrap_or(first);
+        Some((first.clone(), last.clone()))
+    }
+
+    /// Returns a short multi-line report of the schedule: the number of
+    /// courses, the total credits, the number of terms with courses, and
+    /// the first and last of those terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, CreditRange, Schedule, Semester, SemTime};
+    /// #
+    /// let intro = CourseID::new("TEST", 1100);
+    /// let research = CourseID::new("TEST", 4980);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(CourseBuilder::new(&intro).credits(CreditRange::fixed(4)).build());
+    /// catalog.add_course(CourseBuilder::new(&research).credits(CreditRange::new(1, 4)).build());
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new(SemTime::Fall(2019)));
+    /// schedule.add_semester(Semester::new(SemTime::Spring(2020)));
+    /// schedule.add_course(&SemTime::Fall(2019), &intro);
+    /// schedule.add_course(&SemTime::Spring(2020), &research);
+    ///
+    /// assert_eq!(
+    ///     schedule.summary(&catalog),
+    ///     "Courses: 2\nCredits: 5-8\nTerms: 2\nFrom Fall 2019 to Spring 2020\n"
+    /// );
+    /// ```
+    pub fn summary(&self, catalog: &Catalog) -> String {
+        let (min, max) = self
+            .credits_per_semester(catalog)
+            .iter()
+            .fold((0, 0), |(min, max), (_, credits)| {
+                (min + credits.0, max + credits.1)
+            });
+        let terms = self
+            .semesters
+            .values()
+            .filter(|semester| !semester.courses.is_empty())
+            .count();
+
+        let mut summary = format!("Courses: {}\n", self.course_count());
+        if min == max {
+            summary += &format!("Credits: {}\n", min);
+        } else {
+            summary += &format!("Credits: {}-{}\n", min, max);
+        }
+        summary += &format!("Terms: {}\n", terms);
+        if let Some((first, last)) = self.span() {
+            summary += &format!("From {} to {}\n", first, last);
+        }
+
+        summary
+    }
+
+    /// Computes the cost components used to rank this schedule against
+    /// other candidate schedules.  Only semesters containing at least one
+    /// course are counted as terms, and each term's load is its minimum
+    /// number of credits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseID, Schedule, Semester, SemTime};
+    /// #
+    /// let mut semester = Semester::new(SemTime::Fall(2019));
+    /// semester.add_course(&CourseID::new("TEST", 1100));
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(semester);
+    /// schedule.add_semester(Semester::new(SemTime::Spring(2020)));
+    ///
+    /// let cost = schedule.cost(&Catalog::new());
+    ///
+    /// assert_eq!(cost.terms, 1);
+    /// assert_eq!(cost.load_variance, 0.0);
+    /// ```
+    pub fn cost(&self, catalog: &Catalog) -> ScheduleCost {
+        let loads: Vec<f64> = self
+            .semesters
+            .values()
+            .filter(|semester| !semester.courses.is_empty())
+            .map(|semester| f64::from(semester.total_credits(catalog).0))
+            .collect();
+
+        if loads.is_empty() {
+            return ScheduleCost {
+                terms: 0,
+                load_variance: 0.0,
+            };
+        }
+
+        let mean = loads.iter().sum::<f64>() / loads.len() as f64;
+        let load_variance =
+            loads.iter().map(|load| (load - mean).powi(2)).sum::<f64>() / loads.len() as f64;
+
+        ScheduleCost {
+            terms: loads.len(),
+            load_variance,
+        }
+    }
+
+    /// Flattens the schedule into one row per scheduled course, for drawing
+    /// the plan as a timeline.  Rows are sorted by term and then by course,
+    /// and each term index counts from the schedule's first semester.
+    /// Courses missing from the catalog have an empty name and no credits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use myca::{Catalog, CourseBuilder, CourseID, CreditRange, Schedule, Semester, SemTime};
+    /// #
+    /// let intro = CourseID::new("TEST", 1100);
+    /// let lab = CourseID::new("TEST", 1110);
+    /// let advanced = CourseID::new("TEST", 2100);
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_course(
+    ///     CourseBuilder::new(&intro)
+    ///         .name("Intro")
+    ///         .credits(CreditRange::fixed(4))
+    ///         .build(),
+    /// );
+    /// catalog.add_course(CourseBuilder::new(&lab).credits(CreditRange::fixed(1)).build());
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Spring(2020), vec![advanced.clone()]));
+    /// schedule.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![lab.clone(), intro.clone()]));
+    ///
+    /// let rows = schedule.gantt_rows(&catalog);
+    /// let terms: Vec<usize> = rows.iter().map(|row| row.term).collect();
+    /// let courses: Vec<&CourseID> = rows.iter().map(|row| &row.course).collect();
+    ///
+    /// assert_eq!(terms, vec![0, 0, 1]);
+    /// assert_eq!(courses, vec![&intro, &lab, &advanced]);
+    /// assert_eq!(rows[0].name, "Intro");
+    /// assert_eq!(rows[0].credits, CreditRange::fixed(4));
+    /// assert_eq!(rows[2].credits, CreditRange::default());
+    /// ```
+    pub fn gantt_rows(&self, catalog: &Catalog) -> Vec<GanttRow> {
+        self.semesters
+            .values()
+            .enumerate()
+            .flat_map(|(term, semester)| {
+                semester.courses.keys().map(move |coid| {
+                    let course = catalog.get_course(coid);
+                    GanttRow {
+                        term,
+                        course: coid.clone(),
+                        name: course
+                            .map(|course| String::from(course.name()))
+                            .unwrap_or_default(),
+                        credits: course.map(Course::credits).unwrap_or_default(),
                     }
+                })
+            })
+            .collect()
+    }
+}
+
+/// A single course of a schedule laid out on a timeline, as produced by
+/// `Schedule::gantt_rows`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GanttRow {
+    /// The index of the course's semester within the schedule.
+    pub term: usize,
+    /// The course being taken.
+    pub course: CourseID,
+    /// The name of the course from the catalog.
+    pub name: String,
+    /// The credits the course is worth.
+    pub credits: CreditRange,
+}
+
+/// The components used to rank generated schedules.  Lower values are
+/// better for every component.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduleCost {
+    /// The number of semesters which contain at least one course.
+    pub terms: usize,
+    /// The variance of the credit load across those semesters.
+    pub load_variance: f64,
+}
+
+/// Costs are ordered by the number of terms first, then by how evenly the
+/// credit load is distributed.
+impl PartialOrd for ScheduleCost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.terms.cmp(&other.terms) {
+            Ordering::Equal => self.load_variance.partial_cmp(&other.load_variance),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+/// Scores schedules so the best one can be chosen.  Lower scores are
+/// better.
+pub trait ScheduleScorer {
+    fn score(&self, sched: &Schedule, catalog: &Catalog) -> f64;
+}
+
+/// Prefers schedules which contain courses in the fewest semesters.
+pub struct FewestTerms;
+
+impl ScheduleScorer for FewestTerms {
+    fn score(&self, sched: &Schedule, catalog: &Catalog) -> f64 {
+        sched.cost(catalog).terms as f64
+    }
+}
+
+/// Prefers schedules whose credit load is spread most evenly across the
+/// semesters containing courses.
+pub struct BalancedCredits;
+
+impl ScheduleScorer for BalancedCredits {
+    fn score(&self, sched: &Schedule, catalog: &Catalog) -> f64 {
+        sched.cost(catalog).load_variance
+    }
+}
+
+/// Prefers schedules which take courses as early as possible.  The score is
+/// the average position of each course's semester within the schedule.
+pub struct FrontLoaded;
+
+impl ScheduleScorer for FrontLoaded {
+    fn score(&self, sched: &Schedule, _catalog: &Catalog) -> f64 {
+        let (weighted, count) = sched.semesters.values().enumerate().fold(
+            (0, 0),
+            |(weighted, count), (position, semester)| {
+                let courses = semester.courses.len();
+                (weighted + position * courses, count + courses)
+            },
+        );
+
+        if count == 0 {
+            0.0
+        } else {
+            weighted as f64 / count as f64
+        }
+    }
+}
+
+/// Prefers schedules which take each subject's courses in consecutive
+/// semesters.  The score is the total number of semesters skipped between
+/// terms containing courses of the same subject.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::{Catalog, CourseID, Schedule, ScheduleScorer, Semester, SemTime, SubjectContinuity};
+/// #
+/// let intro = CourseID::new("CSCI", 1100);
+/// let data = CourseID::new("CSCI", 1200);
+/// let calc = CourseID::new("MATH", 1010);
+///
+/// let mut together = Schedule::new();
+/// together.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![intro.clone()]));
+/// together.add_semester(Semester::new_with_courses(SemTime::Spring(2020), vec![data.clone()]));
+/// together.add_semester(Semester::new_with_courses(SemTime::Fall(2020), vec![calc.clone()]));
+///
+/// let mut scattered = Schedule::new();
+/// scattered.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![intro]));
+/// scattered.add_semester(Semester::new_with_courses(SemTime::Spring(2020), vec![calc]));
+/// scattered.add_semester(Semester::new_with_courses(SemTime::Fall(2020), vec![data]));
+///
+/// let catalog = Catalog::new();
+/// assert!(SubjectContinuity.score(&together, &catalog) < SubjectContinuity.score(&scattered, &catalog));
+/// assert_eq!(
+///     Schedule::best(&[scattered, together.clone()], &SubjectContinuity, &catalog),
+///     Some(&together)
+/// );
+/// ```
+pub struct SubjectContinuity;
+
+impl ScheduleScorer for SubjectContinuity {
+    fn score(&self, sched: &Schedule, _catalog: &Catalog) -> f64 {
+        let mut positions: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (position, semester) in sched.semesters.values().enumerate() {
+            for coid in semester.courses.keys() {
+                let terms = positions.entry(coid.subj()).or_default();
+                if terms.last() != Some(&position) {
+                    terms.push(position);
                 }
             }
-            all_scheds = curr_set;
         }
 
-        all_scheds
+        let skipped: usize = positions
+            .values()
+            .flat_map(|terms| terms.windows(2).map(|pair| pair[1] - pair[0] - 1))
+            .sum();
+
+        skipped as f64
     }
 }
 
@@ -496,7 +4481,7 @@ impl fmt::Display for Schedule {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut output = String::new();
 
-        for (_, semester) in &self.semesters {
+        for semester in self.semesters.values() {
             output = format!("{}{}", output, semester);
         }
 
@@ -512,7 +4497,334 @@ pub fn get_schedules(
 ) -> Vec<Schedule> {
     schedules
         .iter()
-        .map(|schedule| Schedule::add_course_to_schedule(coid, &schedule, catalog))
-        .flatten()
+        .flat_map(|schedule| Schedule::add_course_to_schedule(coid, schedule, catalog))
         .collect()
 }
+
+/// The version of the schedule file format written by `schedules_to_json`.
+///
+/// - Version 0 files are unversioned: a bare list of schedules, each either
+///   in the flat form of `Schedule::to_flat_json` or the derived form.
+/// - Version 1 files are `{"version": 1, "schedules": [...]}`, with each
+///   schedule written as its list of semesters.
+pub const SCHEDULE_FILE_VERSION: u32 = 1;
+
+/// The error returned when a schedule file cannot be read.
+#[derive(Debug)]
+pub enum ScheduleFileError {
+    /// The file was written by a newer version of the format.
+    UnsupportedVersion(u32),
+    /// The file is an object whose version number is missing or malformed.
+    InvalidVersion,
+    /// The file is not valid for its version of the format.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ScheduleFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScheduleFileError::UnsupportedVersion(version) => write!(
+                f,
+                "schedule file version {} is newer than the supported version {}",
+                version, SCHEDULE_FILE_VERSION
+            ),
+            ScheduleFileError::InvalidVersion => {
+                write!(f, "schedule file has a missing or malformed version")
+            }
+            ScheduleFileError::Json(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for ScheduleFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScheduleFileError::UnsupportedVersion(_) | ScheduleFileError::InvalidVersion => None,
+            ScheduleFileError::Json(err) => Some(err),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ScheduleFileError {
+    fn from(err: serde_json::Error) -> Self {
+        ScheduleFileError::Json(err)
+    }
+}
+
+/// The current schedule file format.
+#[derive(Deserialize, Serialize)]
+struct ScheduleFile {
+    version: u32,
+    schedules: Vec<Vec<Semester>>,
+}
+
+/// Generated schedules together with the configuration which produced
+/// them, for tools which need to know what constraints a plan was built
+/// under.  These are written in the current schedule file format with an
+/// added `config` field, so they can also be read by `schedules_from_json`.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::{schedules_from_json, CourseID, GeneratedPlans, GenerationConfig, Schedule, Semester, SemTime, SummerPolicy};
+/// # use std::time::Duration;
+/// #
+/// let coid = CourseID::new("CSCI", 1100);
+///
+/// let mut config = GenerationConfig::default();
+/// config.completed.insert(CourseID::new("MATH", 1010));
+/// config.pinned.insert(coid.clone(), SemTime::Fall(2019));
+/// config.summer = SummerPolicy::Forbid;
+/// config.max_credits_per_semester = Some(18);
+/// config.timeout = Some(Duration::from_secs(5));
+///
+/// let mut schedule = Schedule::new();
+/// schedule.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![coid]));
+///
+/// let plans = GeneratedPlans {
+///     config: config.clone(),
+///     schedules: vec![schedule.clone()],
+/// };
+///
+/// let json = serde_json::to_string(&plans).unwrap();
+/// let reloaded: GeneratedPlans = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(reloaded.config, config);
+/// assert_eq!(reloaded.schedules, vec![schedule.clone()]);
+///
+/// let value = serde_json::from_str(&json).unwrap();
+/// assert_eq!(schedules_from_json(value).unwrap(), vec![schedule]);
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(try_from = "GeneratedPlansRepr", into = "GeneratedPlansRepr")]
+pub struct GeneratedPlans {
+    /// The configuration the schedules were generated with.
+    pub config: GenerationConfig,
+    /// The generated schedules.
+    pub schedules: Vec<Schedule>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct GeneratedPlansRepr {
+    version: u32,
+    config: GenerationConfig,
+    schedules: Vec<Vec<Semester>>,
+}
+
+impl TryFrom<GeneratedPlansRepr> for GeneratedPlans {
+    type Error = ScheduleFileError;
+
+    fn try_from(repr: GeneratedPlansRepr) -> Result<Self, Self::Error> {
+        if repr.version > SCHEDULE_FILE_VERSION {
+            return Err(ScheduleFileError::UnsupportedVersion(repr.version));
+        }
+
+        Ok(GeneratedPlans {
+            config: repr.config,
+            schedules: repr
+                .schedules
+                .into_iter()
+                .map(schedule_from_semesters)
+                .collect(),
+        })
+    }
+}
+
+impl From<GeneratedPlans> for GeneratedPlansRepr {
+    fn from(plans: GeneratedPlans) -> Self {
+        GeneratedPlansRepr {
+            version: SCHEDULE_FILE_VERSION,
+            config: plans.config,
+            schedules: plans
+                .schedules
+                .into_iter()
+                .map(|schedule| schedule.semesters.into_values().collect())
+                .collect(),
+        }
+    }
+}
+
+/// Builds a schedule from its list of semesters.
+fn schedule_from_semesters(semesters: Vec<Semester>) -> Schedule {
+    let mut schedule = Schedule::new();
+    for semester in semesters {
+        schedule.add_semester(semester);
+    }
+    schedule
+}
+
+/// Writes schedules in the current schedule file format.
+pub fn schedules_to_json(schedules: &[Schedule]) -> serde_json::Value {
+    let file = ScheduleFile {
+        version: SCHEDULE_FILE_VERSION,
+        schedules: schedules
+            .iter()
+            .map(|schedule| schedule.semesters.values().cloned().collect())
+            .collect(),
+    };
+
+    serde_json::to_value(file).expect("schedules always serialize")
+}
+
+/// Reads schedules from any version of the schedule file format, migrating
+/// older versions as they are read.  A bare list of schedules is an
+/// unversioned legacy file, and is read as version 0.  Files from a newer
+/// version are rejected.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::{schedules_from_json, schedules_to_json, CourseID, ScheduleFileError, SemTime};
+/// # use serde_json::json;
+/// #
+/// // An unversioned file from before the version marker was added
+/// let legacy = json!([
+///     [{ "term": "Fall 2019", "courses": ["CSCI 1100"] }],
+///     { "semesters": {} }
+/// ]);
+///
+/// let schedules = schedules_from_json(legacy).unwrap();
+/// assert_eq!(schedules.len(), 2);
+/// assert_eq!(schedules[0].get_time(&CourseID::new("CSCI", 1100)), Some(&SemTime::Fall(2019)));
+/// assert!(schedules[1].semesters().is_empty());
+///
+/// // Once upgraded, the schedules round trip through the current format
+/// let upgraded = schedules_to_json(&schedules);
+/// assert_eq!(upgraded["version"], 1);
+/// assert_eq!(schedules_from_json(upgraded).unwrap(), schedules);
+///
+/// // Version 0 files are migrated like unversioned ones
+/// let tagged = json!({
+///     "version": 0,
+///     "schedules": [[{ "term": "Fall 2019", "courses": ["CSCI 1100"] }]]
+/// });
+/// assert_eq!(schedules_from_json(tagged).unwrap()[0], schedules[0]);
+///
+/// let future = json!({ "version": 99, "schedules": [] });
+/// assert!(matches!(
+///     schedules_from_json(future),
+///     Err(ScheduleFileError::UnsupportedVersion(99))
+/// ));
+///
+/// let unmarked = json!({ "version": "one", "schedules": [] });
+/// assert!(matches!(
+///     schedules_from_json(unmarked),
+///     Err(ScheduleFileError::InvalidVersion)
+/// ));
+/// ```
+pub fn schedules_from_json(value: serde_json::Value) -> Result<Vec<Schedule>, ScheduleFileError> {
+    let legacy = match value {
+        serde_json::Value::Array(entries) => entries,
+        mut value => {
+            let version = value
+                .get("version")
+                .and_then(serde_json::Value::as_u64)
+                .ok_or(ScheduleFileError::InvalidVersion)?;
+            if version > u64::from(SCHEDULE_FILE_VERSION) {
+                let version = u32::try_from(version).unwrap_or(u32::MAX);
+                return Err(ScheduleFileError::UnsupportedVersion(version));
+            }
+
+            if version == 0 {
+                Vec::deserialize(value["schedules"].take())?
+            } else {
+                let file = ScheduleFile::deserialize(value)?;
+                return Ok(file
+                    .schedules
+                    .into_iter()
+                    .map(schedule_from_semesters)
+                    .collect());
+            }
+        }
+    };
+
+    legacy
+        .into_iter()
+        .map(|entry| match entry {
+            serde_json::Value::Array(_) => Schedule::from_flat_json(&entry),
+            entry => Schedule::deserialize(entry),
+        })
+        .collect::<Result<_, _>>()
+        .map_err(ScheduleFileError::from)
+}
+
+/// Sorts schedules from best to worst according to the given scorer.
+/// Schedules with equal scores keep their relative order.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::{rank_schedules_by, Catalog, CourseID, Schedule, Semester, SemTime, SubjectContinuity};
+/// #
+/// let intro = CourseID::new("CSCI", 1100);
+/// let data = CourseID::new("CSCI", 1200);
+///
+/// let mut scattered = Schedule::new();
+/// scattered.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![intro.clone()]));
+/// scattered.add_semester(Semester::new(SemTime::Spring(2020)));
+/// scattered.add_semester(Semester::new_with_courses(SemTime::Fall(2020), vec![data.clone()]));
+///
+/// let mut together = Schedule::new();
+/// together.add_semester(Semester::new_with_courses(SemTime::Fall(2019), vec![intro]));
+/// together.add_semester(Semester::new_with_courses(SemTime::Spring(2020), vec![data]));
+///
+/// let ranked = rank_schedules_by(vec![scattered, together.clone()], &SubjectContinuity, &Catalog::new());
+/// assert_eq!(ranked[0], together);
+/// ```
+pub fn rank_schedules_by<S: ScheduleScorer>(
+    schedules: Vec<Schedule>,
+    scorer: &S,
+    catalog: &Catalog,
+) -> Vec<Schedule> {
+    let mut scored: Vec<(f64, Schedule)> = schedules
+        .into_iter()
+        .map(|schedule| (scorer.score(&schedule, catalog), schedule))
+        .collect();
+
+    scored.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    scored.into_iter().map(|(_, schedule)| schedule).collect()
+}
+
+/// Sorts schedules from best to worst according to their `ScheduleCost`:
+/// schedules spanning fewer terms come first, with ties broken by the most
+/// balanced credit load.
+///
+/// # Examples
+///
+/// ```
+/// # use myca::{rank_schedules, Catalog, CourseID, Schedule, Semester, SemTime};
+/// #
+/// fn spread_over(terms: i32) -> Schedule {
+///     let mut schedule = Schedule::new();
+///     for (i, code) in (1100..1900).step_by(100).enumerate() {
+///         let year = 2019 + (i as i32 % terms);
+///         let mut semester = match schedule.get_semester(&SemTime::Fall(year)) {
+///             Some(semester) => semester.clone(),
+///             None => Semester::new(SemTime::Fall(year)),
+///         };
+///         semester.add_course(&CourseID::new("TEST", code));
+///         schedule.add_semester(semester);
+///     }
+///     schedule
+/// }
+///
+/// let catalog = Catalog::new();
+/// let six_terms = spread_over(6);
+/// let eight_terms = spread_over(8);
+///
+/// let ranked = rank_schedules(vec![eight_terms.clone(), six_terms.clone()], &catalog);
+///
+/// assert_eq!(ranked[0].cost(&catalog).terms, 6);
+/// assert_eq!(ranked[1].cost(&catalog).terms, 8);
+/// ```
+pub fn rank_schedules(schedules: Vec<Schedule>, catalog: &Catalog) -> Vec<Schedule> {
+    let mut costed: Vec<(ScheduleCost, Schedule)> = schedules
+        .into_iter()
+        .map(|schedule| (schedule.cost(catalog), schedule))
+        .collect();
+
+    costed.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    costed.into_iter().map(|(_, schedule)| schedule).collect()
+}
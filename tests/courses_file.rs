@@ -0,0 +1,67 @@
+use std::env;
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn courses_file_adds_every_course() {
+    let catalog_path = env::temp_dir().join(format!(
+        "myca_courses_file_catalog_{}.json",
+        std::process::id()
+    ));
+    let courses_path = env::temp_dir().join(format!(
+        "myca_courses_file_courses_{}.txt",
+        std::process::id()
+    ));
+    let course = |code: u16| {
+        format!(
+            r#"{{
+                "complete": true,
+                "name": "Testing",
+                "description": "",
+                "offered": "",
+                "age_reqs": "",
+                "prereqs": [],
+                "prereqs_opt": [],
+                "coreqs": [],
+                "coreqs_opt": [],
+                "post_options": [],
+                "coid": {{"subj": "TEST", "code": {}}}
+            }}"#,
+            code
+        )
+    };
+    fs::write(
+        &catalog_path,
+        format!(
+            r#"{{"courses": [{}, {}, {}]}}"#,
+            course(1100),
+            course(1200),
+            course(1300)
+        ),
+    )
+    .unwrap();
+    fs::write(
+        &courses_path,
+        "# first year\nTEST 1100\n\nTEST-1200\nnot a course\nTEST1300\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_main"))
+        .arg("--catalog")
+        .arg(&catalog_path)
+        .arg("--courses-file")
+        .arg(&courses_path)
+        .output()
+        .unwrap();
+
+    fs::remove_file(&catalog_path).unwrap();
+    fs::remove_file(&courses_path).unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success());
+    assert!(stdout.contains("schedule(s) for TEST 1100"));
+    assert!(stdout.contains("schedule(s) for TEST 1200"));
+    assert!(stdout.contains("schedule(s) for TEST 1300"));
+    assert!(stderr.contains("'not a course' is not in the Course ID format"));
+}
@@ -0,0 +1,42 @@
+use std::env;
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn explain_mentions_missing_prereq() {
+    let catalog_path =
+        env::temp_dir().join(format!("myca_explain_catalog_{}.json", std::process::id()));
+    fs::write(
+        &catalog_path,
+        r#"{
+            "courses": [{
+                "complete": true,
+                "name": "Advanced Testing",
+                "description": "",
+                "offered": "",
+                "age_reqs": "",
+                "prereqs": [[{"subj": "TEST", "code": 1100}]],
+                "prereqs_opt": [],
+                "coreqs": [],
+                "coreqs_opt": [],
+                "post_options": [],
+                "coid": {"subj": "TEST", "code": 1200}
+            }]
+        }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_main"))
+        .arg("--catalog")
+        .arg(&catalog_path)
+        .arg("--explain")
+        .arg("TEST 1200")
+        .output()
+        .unwrap();
+
+    fs::remove_file(&catalog_path).unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Found 0 schedule(s) for TEST 1200"));
+    assert!(stdout.contains("TEST 1200 requires undefined course TEST 1100"));
+}
@@ -0,0 +1,64 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use myca::{Catalog, CourseBuilder, CourseID};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const SUBJECTS: [&str; 8] = [
+    "ARTS", "BIOL", "CHEM", "CSCI", "ECON", "MATH", "PHYS", "PSYC",
+];
+
+#[test]
+fn interned_course_ids_clone_without_allocating() {
+    let coids: Vec<CourseID> = (0..10_000u16)
+        .map(|i| CourseID::new(SUBJECTS[usize::from(i) % SUBJECTS.len()], 1000 + i))
+        .collect();
+
+    let mut catalog = Catalog::new();
+    for coid in &coids {
+        catalog.add_course(CourseBuilder::new(coid).build());
+    }
+
+    // Only the vector holding the clones may allocate; before interning
+    // every clone allocated its own subject string.
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let cloned: Vec<CourseID> = coids
+        .iter()
+        .map(|coid| catalog.get_course(coid).unwrap().get_id())
+        .collect();
+    let allocations = ALLOCATIONS.load(Ordering::SeqCst) - before;
+
+    assert_eq!(allocations, 1);
+    assert_eq!(cloned, coids);
+
+    for coid in &coids {
+        let course = catalog.get_course(coid).unwrap();
+        assert_eq!(&course.get_id(), coid);
+        assert_eq!(
+            course.get_id().to_string().parse::<CourseID>(),
+            Ok(coid.clone())
+        );
+    }
+
+    assert_eq!(coids[3].to_string(), "CSCI 1003");
+    assert_eq!(coids[3], CourseID::new("CSCI", 1003));
+    assert_ne!(coids[3], CourseID::new("MATH", 1003));
+    assert!(CourseID::new("CSCI", 9999) < CourseID::new("MATH", 1000));
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use myca::{Catalog, CourseBuilder, CourseID, GenerationConfig, Schedule, SemTime, Semester};
+
+/// Builds a capstone which requires the ends of two three-course chains,
+/// so it needs at least four terms, along with eight empty semesters.
+fn deep_tree() -> (Catalog, CourseID, Schedule) {
+    let mut catalog = Catalog::new();
+    let mut ends = Vec::new();
+
+    for subj in &["MATH", "PHYS"] {
+        let mut previous: Option<CourseID> = None;
+        for level in 1..=3 {
+            let coid = CourseID::new(subj, level * 1000);
+            let mut builder = CourseBuilder::new(&coid);
+            if let Some(prereq) = previous {
+                builder = builder.prereq_group(vec![prereq]);
+            }
+            catalog.add_course(builder.build());
+            previous = Some(coid);
+        }
+        ends.push(previous.unwrap());
+    }
+
+    let capstone = CourseID::new("ENGR", 4000);
+    let mut builder = CourseBuilder::new(&capstone);
+    for end in ends {
+        builder = builder.prereq_group(vec![end]);
+    }
+    catalog.add_course(builder.build());
+
+    let mut skeleton = Schedule::new();
+    let mut time = SemTime::Fall(2019);
+    for _ in 0..8 {
+        skeleton.add_semester(Semester::new(time.clone()));
+        time = time.next();
+    }
+
+    (catalog, capstone, skeleton)
+}
+
+#[test]
+fn max_terms_prunes_longer_schedules() {
+    let (catalog, capstone, skeleton) = deep_tree();
+
+    let all = Schedule::add_course_to_schedule_with_config(
+        &capstone,
+        &skeleton,
+        &catalog,
+        &GenerationConfig::default(),
+    );
+
+    let constrained = GenerationConfig {
+        max_terms: Some(4),
+        ..GenerationConfig::default()
+    };
+    let short =
+        Schedule::add_course_to_schedule_with_config(&capstone, &skeleton, &catalog, &constrained);
+
+    assert!(!short.is_empty());
+    for schedule in &short {
+        assert!(schedule.contains(&capstone));
+        assert!(schedule.cost(&catalog).terms <= 4);
+    }
+
+    // Pruning only drops the longer schedules, which are most of them
+    let expected: HashSet<&Schedule> = all
+        .iter()
+        .filter(|schedule| schedule.cost(&catalog).terms <= 4)
+        .collect();
+    assert_eq!(short.iter().collect::<HashSet<_>>(), expected);
+    assert_eq!(short.len(), 70);
+    assert_eq!(all.len(), 1742);
+}
@@ -0,0 +1,26 @@
+use myca::{Catalog, CourseBuilder, CourseID};
+
+#[test]
+fn add_course_records_dependents_as_post_options() {
+    let intro = CourseID::new("CSCI", 1100);
+    let calc = CourseID::new("MATH", 1010);
+    let data = CourseID::new("CSCI", 1200);
+
+    let mut catalog = Catalog::new();
+    catalog.add_course(CourseBuilder::new(&intro).build());
+    catalog.add_course(
+        CourseBuilder::new(&data)
+            .prereq_group(vec![intro.clone()])
+            .prereq_group(vec![calc.clone()])
+            .build(),
+    );
+
+    // Both the existing prereq and the placeholder created for the missing
+    // one point at the course requiring them, not at themselves
+    for prereq in &[&intro, &calc] {
+        let post_options = catalog.get_course(prereq).unwrap().post_options();
+        assert_eq!(post_options.len(), 1);
+        assert!(post_options.contains(&data));
+    }
+    assert!(catalog.get_course(&data).unwrap().post_options().is_empty());
+}
@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+
+use myca::{Catalog, CourseBuilder, CourseID, Schedule, SemTime, Semester};
+
+/// The recursive generator which `Schedule::add_course_to_schedule` replaced,
+/// kept as a reference for its results.
+fn recursive(coid: &CourseID, sched: &Schedule, catalog: &Catalog) -> Vec<Schedule> {
+    let course = match catalog.get_course(coid) {
+        Some(course) => course,
+        None => return Vec::new(),
+    };
+
+    let mut prereq_scheds = vec![sched.clone()];
+    for prereq_set in course.prereq_sets() {
+        let mut curr_set = Vec::new();
+        for prereq in prereq_set {
+            for schedule in &prereq_scheds {
+                if schedule.contains(prereq) {
+                    curr_set.push(schedule.clone());
+                } else {
+                    curr_set.append(&mut recursive(prereq, schedule, catalog));
+                }
+            }
+        }
+        prereq_scheds = curr_set;
+    }
+
+    let mut all_scheds = Vec::new();
+    for sched in prereq_scheds {
+        for time in sched.semesters().keys() {
+            if let Some(new_sched) = try_add(&sched, coid, time, catalog) {
+                all_scheds.push(new_sched);
+            }
+        }
+    }
+
+    for coreq_set in course.coreq_sets() {
+        let mut curr_set = Vec::new();
+        for coreq in coreq_set {
+            for schedule in &all_scheds {
+                if schedule.contains(coreq) {
+                    curr_set.push(schedule.clone());
+                } else {
+                    curr_set.append(&mut recursive(coreq, schedule, catalog));
+                }
+            }
+        }
+        all_scheds = curr_set;
+    }
+
+    all_scheds
+}
+
+fn try_add(
+    sched: &Schedule,
+    coid: &CourseID,
+    sem: &SemTime,
+    catalog: &Catalog,
+) -> Option<Schedule> {
+    if sched.contains(coid) {
+        return Some(sched.clone());
+    }
+
+    let course = catalog.get_course(coid).unwrap();
+
+    for coreq_set in course.coreq_sets() {
+        let times: Vec<&SemTime> = coreq_set
+            .iter()
+            .filter_map(|coreq| sched.get_time(coreq))
+            .collect();
+        if !times.is_empty() && !times.contains(&sem) {
+            return None;
+        }
+    }
+
+    for prereq_set in course.prereq_sets() {
+        let met = prereq_set.iter().any(|prereq| {
+            sched
+                .semesters()
+                .range(..sem.clone())
+                .any(|(_, semester)| semester.contains(prereq))
+        });
+        if !met {
+            return None;
+        }
+    }
+
+    let mut new_sched = sched.clone();
+    new_sched.add_course(sem, coid);
+    Some(new_sched)
+}
+
+fn counts(schedules: Vec<Schedule>) -> HashMap<Schedule, usize> {
+    let mut counts = HashMap::new();
+    for schedule in schedules {
+        *counts.entry(schedule).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn skeleton(terms: i32) -> Schedule {
+    let mut skeleton = Schedule::new();
+    for year in 0..terms {
+        skeleton.add_semester(Semester::new(SemTime::Fall(2019 + year)));
+    }
+    skeleton
+}
+
+fn assert_matches_recursive(goal: &CourseID, catalog: &Catalog, terms: i32) {
+    let skeleton = skeleton(terms);
+    let expected = recursive(goal, &skeleton, catalog);
+    let generated = Schedule::add_course_to_schedule(goal, &skeleton, catalog);
+
+    assert!(!expected.is_empty());
+    assert_eq!(counts(generated), counts(expected));
+}
+
+#[test]
+fn chains_match_recursive_generation() {
+    let mut catalog = Catalog::new();
+    for code in 0..4 {
+        let coid = CourseID::new("CHAIN", code);
+        let mut builder = CourseBuilder::new(&coid);
+        if code > 0 {
+            builder = builder.prereq_group(vec![CourseID::new("CHAIN", code - 1)]);
+        }
+        catalog.add_course(builder.build());
+    }
+
+    assert_matches_recursive(&CourseID::new("CHAIN", 3), &catalog, 6);
+}
+
+#[test]
+fn alternatives_match_recursive_generation() {
+    let base = CourseID::new("ALT", 1000);
+    let left = CourseID::new("ALT", 2100);
+    let right = CourseID::new("ALT", 2200);
+    let extra = CourseID::new("ALT", 2300);
+    let other = CourseID::new("ALT", 3100);
+    let goal = CourseID::new("ALT", 4000);
+
+    let mut catalog = Catalog::new();
+    catalog.add_course(CourseBuilder::new(&base).build());
+    catalog.add_course(CourseBuilder::new(&extra).build());
+    catalog.add_course(
+        CourseBuilder::new(&left)
+            .prereq_group(vec![base.clone()])
+            .build(),
+    );
+    catalog.add_course(
+        CourseBuilder::new(&right)
+            .prereq_group(vec![base.clone()])
+            .build(),
+    );
+    catalog.add_course(
+        CourseBuilder::new(&other)
+            .prereq_group(vec![right.clone(), extra.clone()])
+            .build(),
+    );
+    catalog.add_course(
+        CourseBuilder::new(&goal)
+            .prereq_group(vec![left.clone(), right.clone(), extra.clone()])
+            .prereq_group(vec![other.clone(), base.clone()])
+            .build(),
+    );
+
+    assert_matches_recursive(&goal, &catalog, 5);
+}
+
+#[test]
+fn corequisites_match_recursive_generation() {
+    let intro = CourseID::new("LAB", 1000);
+    let lab = CourseID::new("LAB", 1010);
+    let lecture = CourseID::new("LAB", 2000);
+
+    let mut catalog = Catalog::new();
+    catalog.add_course(CourseBuilder::new(&intro).build());
+    catalog.add_course(
+        CourseBuilder::new(&lab)
+            .prereq_group(vec![intro.clone()])
+            .build(),
+    );
+    catalog.add_course(
+        CourseBuilder::new(&lecture)
+            .prereq_group(vec![intro.clone()])
+            .coreq_group(vec![lab.clone()])
+            .build(),
+    );
+
+    assert_matches_recursive(&lecture, &catalog, 4);
+}
+
+#[test]
+fn alternatives_are_tried_in_sorted_order() {
+    let options: Vec<CourseID> = (0..8).map(|code| CourseID::new("ORDER", code)).collect();
+    let goal = CourseID::new("ORDER", 100);
+
+    let build = || {
+        let mut catalog = Catalog::new();
+        for option in &options {
+            catalog.add_course(CourseBuilder::new(option).build());
+        }
+        catalog.add_course(
+            CourseBuilder::new(&goal)
+                .prereq_group(options.clone())
+                .build(),
+        );
+        catalog
+    };
+
+    let skeleton = skeleton(2);
+    let first = Schedule::add_course_to_schedule(&goal, &skeleton, &build());
+    let second = Schedule::add_course_to_schedule(&goal, &skeleton, &build());
+
+    assert_eq!(first, second);
+    let chosen: Vec<&CourseID> = first
+        .iter()
+        .map(|schedule| {
+            options
+                .iter()
+                .find(|option| schedule.contains(option))
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(chosen, options.iter().collect::<Vec<_>>());
+}
@@ -0,0 +1,73 @@
+use std::env;
+use std::fs::{self, File};
+use std::io::BufReader;
+
+use myca::{parse_catalog_file, parse_catalog_streaming, Catalog, CourseID, Season};
+
+const COURSES: u16 = 5_000;
+
+fn course_json(code: u16) -> String {
+    let prereqs = if code > 0 {
+        format!(r#"[["TEST {}", "TEST {}"]]"#, code - 1, code / 2)
+    } else {
+        String::from("[]")
+    };
+
+    format!(
+        r#"{{
+            "complete": true,
+            "name": "Synthetic Course {code}",
+            "description": "",
+            "offered": "",
+            "age_reqs": "",
+            "prereqs": {prereqs},
+            "prereqs_opt": [],
+            "coreqs": [],
+            "coreqs_opt": [],
+            "post_options": [],
+            "coid": "TEST {code}"
+        }}"#,
+        code = code,
+        prereqs = prereqs
+    )
+}
+
+fn describe(catalog: &Catalog) -> Vec<(CourseID, String, Vec<CourseID>)> {
+    catalog
+        .courses_offered_in(Season::Fall)
+        .into_iter()
+        .map(|course| {
+            let mut post_options: Vec<CourseID> = course.post_options().iter().cloned().collect();
+            post_options.sort();
+            (course.get_id(), course.name().to_string(), post_options)
+        })
+        .collect()
+}
+
+#[test]
+fn streaming_parse_matches_file_parse() {
+    let path = env::temp_dir().join(format!(
+        "myca_streaming_parse_catalog_{}.json",
+        std::process::id()
+    ));
+    let courses: Vec<String> = (0..COURSES).map(course_json).collect();
+    fs::write(
+        &path,
+        format!(r#"{{"version": 2, "courses": [{}]}}"#, courses.join(",")),
+    )
+    .unwrap();
+
+    let eager = parse_catalog_file(&path).unwrap();
+    let streamed = parse_catalog_streaming(BufReader::new(File::open(&path).unwrap())).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(describe(&streamed).len(), usize::from(COURSES));
+    assert_eq!(describe(&streamed), describe(&eager));
+    assert_eq!(streamed.prereq_edges(), eager.prereq_edges());
+}
+
+#[test]
+fn streaming_parse_requires_courses() {
+    assert!(parse_catalog_streaming(r#"{"version": 2}"#.as_bytes()).is_err());
+    assert!(parse_catalog_streaming(r#"{"courses": []} trailing"#.as_bytes()).is_err());
+}
@@ -0,0 +1,37 @@
+use myca::CourseID;
+
+fn subject(mut index: usize) -> String {
+    let mut subj = String::new();
+    for _ in 0..4 {
+        subj.push(char::from(b'A' + (index % 26) as u8));
+        index /= 26;
+    }
+    subj
+}
+
+#[test]
+fn subjects_past_the_limit_are_rejected_instead_of_panicking() {
+    let mut index = 0;
+    while format!("{} 1100", subject(index))
+        .parse::<CourseID>()
+        .is_ok()
+    {
+        index += 1;
+    }
+    assert_eq!(index, 1 << 16);
+
+    let overflow = subject(index);
+    let err = format!("{} 1100", overflow)
+        .parse::<CourseID>()
+        .unwrap_err();
+    assert!(err.to_string().contains("distinct course subjects"));
+    assert_eq!(CourseID::from(&format!("{}-1100", overflow)), None);
+    assert!(serde_json::from_str::<CourseID>(&format!(
+        r#"{{"subj": "{}", "code": 1100}}"#,
+        overflow
+    ))
+    .is_err());
+
+    let known: CourseID = format!("{} 1100", subject(0)).parse().unwrap();
+    assert_eq!(known.subj(), subject(0));
+}
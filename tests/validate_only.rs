@@ -0,0 +1,51 @@
+use std::env;
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn validate_only_fails_on_cycle() {
+    let catalog_path = env::temp_dir().join(format!(
+        "myca_validate_only_catalog_{}.json",
+        std::process::id()
+    ));
+    let course = |code: u16, prereq: u16| {
+        format!(
+            r#"{{
+                "complete": true,
+                "name": "Cyclic Testing",
+                "description": "",
+                "offered": "",
+                "age_reqs": "",
+                "prereqs": [[{{"subj": "TEST", "code": {}}}]],
+                "prereqs_opt": [],
+                "coreqs": [],
+                "coreqs_opt": [],
+                "post_options": [],
+                "coid": {{"subj": "TEST", "code": {}}}
+            }}"#,
+            prereq, code
+        )
+    };
+    fs::write(
+        &catalog_path,
+        format!(
+            r#"{{"courses": [{}, {}]}}"#,
+            course(1100, 1200),
+            course(1200, 1100)
+        ),
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_main"))
+        .arg("--catalog")
+        .arg(&catalog_path)
+        .arg("--validate-only")
+        .output()
+        .unwrap();
+
+    fs::remove_file(&catalog_path).unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!output.status.success());
+    assert!(stdout.contains("TEST 1100 is part of a prerequisite cycle"));
+}